@@ -0,0 +1,47 @@
+//! Marker types describing how a register's fields may be accessed.
+//!
+//! Each field module generated by `register!` carries one of these as its
+//! `Access` type.
+
+/// `Access` is implemented by every access marker.
+pub trait Access {
+    /// Whether writing a one to the field clears it, and writing a zero
+    /// leaves it be.
+    const W1C: bool;
+
+    /// Whether the field may be written.
+    const WRITABLE: bool;
+}
+
+/// The field may only be read.
+pub struct RO;
+
+/// The field may only be written.
+pub struct WO;
+
+/// The field may be both read and written.
+pub struct RW;
+
+/// The field latches a flag which is cleared by writing a one to it;
+/// writing a zero is a no-op.
+pub struct W1C;
+
+impl Access for RO {
+    const W1C: bool = false;
+    const WRITABLE: bool = false;
+}
+
+impl Access for WO {
+    const W1C: bool = false;
+    const WRITABLE: bool = true;
+}
+
+impl Access for RW {
+    const W1C: bool = false;
+    const WRITABLE: bool = true;
+}
+
+impl Access for W1C {
+    const W1C: bool = true;
+    const WRITABLE: bool = true;
+}
@@ -1,7 +1,27 @@
 use core::marker::PhantomData;
+use core::ops::{BitAnd, BitOr, Not, Shl, Shr, Sub};
 
-use typenum::consts::True;
-use typenum::{IsGreater, IsGreaterOrEqual, IsLessOrEqual, Unsigned};
+use typenum::consts::{True, U1};
+use typenum::{Diff, IsGreater, IsGreaterOrEqual, IsLessOrEqual, Shleft, Unsigned};
+
+/// `widths` invokes `$m!` once for each numeric type a register may
+/// be built over. Every layer that's implemented per width goes
+/// through it, so that none of them can cover a width the others
+/// don't.
+macro_rules! widths {
+    ($m:ident) => {
+        $m!(u8);
+        $m!(u16);
+        $m!(u32);
+        $m!(u64);
+        $m!(u128);
+        $m!(usize);
+        $m!(i8);
+        $m!(i16);
+        $m!(i32);
+        $m!(i64);
+    };
+}
 
 /// A type whose behaviors enforce that its `val` member fall with in
 /// the range prescribed by `L` (a lower bound) and `U` (an upper
@@ -39,9 +59,9 @@ macro_rules! boundeds {
     ($num_type:ty) => {
         impl<L, U> Bounded<$num_type, L, U> {
             /// Compile-type checked value.
-            pub const fn checked<V: Unsigned>() -> Self
+            pub const fn checked<V>() -> Self
             where
-                V: IsLessOrEqual<U, Output = True>,
+                V: Unsigned + IsLessOrEqual<U, Output = True>,
                 V: IsGreaterOrEqual<L, Output = True>,
             {
                 Self {
@@ -54,11 +74,7 @@ macro_rules! boundeds {
     };
 }
 
-boundeds!(u8);
-boundeds!(u16);
-boundeds!(u32);
-boundeds!(u64);
-boundeds!(usize);
+widths!(boundeds);
 
 /// `Reify` is basically `From`, but both types are foreign so we have
 /// to make a new trait. It's the last peice to our numeric-like
@@ -70,30 +86,147 @@ pub trait ReifyTo<T> {
     fn reify() -> T;
 }
 
-impl<T: Unsigned> ReifyTo<u8> for T {
-    fn reify() -> u8 {
-        T::U8
+impl<T: Unsigned, W: RegisterWidth> ReifyTo<W> for T {
+    fn reify() -> W {
+        W::from_unsigned::<T>()
     }
 }
 
-impl<T: Unsigned> ReifyTo<u16> for T {
-    fn reify() -> u16 {
-        T::U16
-    }
+/// `Span` is implemented by every type-level number `N`, and gives the
+/// largest value `N` bits can hold, `2^N - 1`, as `Max`. Naming it
+/// through a trait, rather than spelling out the arithmetic in a bound,
+/// keeps the compiler from exploring the arithmetic before `N` is known.
+///
+/// *Note*: You probably don't want to use this directly.
+pub trait Span {
+    type Max;
 }
 
-impl<T: Unsigned> ReifyTo<u32> for T {
-    fn reify() -> u32 {
-        T::U32
-    }
+impl<N> Span for N
+where
+    U1: Shl<N>,
+    Shleft<U1, N>: Sub<U1>,
+{
+    type Max = Diff<Shleft<U1, N>, U1>;
 }
 
-impl<T: Unsigned> ReifyTo<usize> for T {
-    fn reify() -> usize {
-        T::USIZE
-    }
+/// `RegisterWidth` is implemented by every numeric type a register may
+/// be built over, and gathers what generic code needs of one: its size
+/// in bits, the bitwise operations fields are built from, and the
+/// conversion from a type-level number. It is sealed; the widths are
+/// those listed in `widths!`.
+///
+/// `Bounded::checked`, `Field::checked`, and `Reifier::reify` must be
+/// `const`, which a trait's methods can't be, so `widths!` still
+/// implements those for each width on its own.
+pub trait RegisterWidth:
+    sealed::Sealed
+    + Copy
+    + PartialOrd
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+    + Shl<Self, Output = Self>
+    + Shr<Self, Output = Self>
+{
+    /// The size of the width in bits.
+    const BITS: u32;
+
+    /// `BITS` as a type-level number.
+    type Bits: Unsigned;
+
+    /// The most bits whose values can be bounded in this width: all of
+    /// them for an unsigned width, and all but the sign bit for a
+    /// signed one.
+    type BoundedBits: Unsigned;
+
+    /// `from_unsigned` converts the type-level number `T` to this width.
+    fn from_unsigned<T: Unsigned>() -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
 }
 
+#[cfg(target_pointer_width = "16")]
+type UsizeBits = typenum::consts::U16;
+#[cfg(target_pointer_width = "32")]
+type UsizeBits = typenum::consts::U32;
+#[cfg(target_pointer_width = "64")]
+type UsizeBits = typenum::consts::U64;
+
+/// `bits_of` names the size in bits of each width as a type-level
+/// number, since `BITS` can't be lifted to one.
+macro_rules! bits_of {
+    (u8) => {
+        typenum::consts::U8
+    };
+    (u16) => {
+        typenum::consts::U16
+    };
+    (u32) => {
+        typenum::consts::U32
+    };
+    (u64) => {
+        typenum::consts::U64
+    };
+    (u128) => {
+        typenum::consts::U128
+    };
+    (usize) => {
+        UsizeBits
+    };
+    (i8) => {
+        typenum::consts::U8
+    };
+    (i16) => {
+        typenum::consts::U16
+    };
+    (i32) => {
+        typenum::consts::U32
+    };
+    (i64) => {
+        typenum::consts::U64
+    };
+    (@bounded i8) => {
+        typenum::consts::U7
+    };
+    (@bounded i16) => {
+        typenum::consts::U15
+    };
+    (@bounded i32) => {
+        typenum::consts::U31
+    };
+    (@bounded i64) => {
+        typenum::consts::U63
+    };
+    (@bounded $num_type:ident) => {
+        bits_of!($num_type)
+    };
+}
+
+macro_rules! register_width {
+    ($num_type:ident) => {
+        paste::paste! {
+            impl sealed::Sealed for $num_type {}
+
+            impl RegisterWidth for $num_type {
+                const BITS: u32 = $num_type::BITS;
+
+                type Bits = bits_of!($num_type);
+
+                type BoundedBits = bits_of!(@bounded $num_type);
+
+                fn from_unsigned<T: Unsigned>() -> $num_type {
+                    T::[<$num_type:upper>]
+                }
+            }
+        }
+    };
+}
+
+widths!(register_width);
+
 /// We have to jump through some hoops to get types to
 /// align. `Reifier` is a parametric version of something like `From`
 /// that we can use to implement `reify()` as a const function; you'll
@@ -107,26 +240,48 @@ pub struct Reifier<U: Unsigned, T> {
 }
 
 macro_rules! reifier {
-    ($num_type:ty, $unsigned:ident) => {
-        impl<U: Unsigned> Reifier<U, $num_type> {
-            pub const fn reify() -> $num_type {
-                U::$unsigned
+    ($num_type:ident) => {
+        paste::paste! {
+            impl<U: Unsigned> Reifier<U, $num_type> {
+                pub const fn reify() -> $num_type {
+                    U::[<$num_type:upper>]
+                }
             }
         }
     };
 }
 
-reifier!(u8, U8);
-reifier!(u16, U16);
-reifier!(u32, U32);
-reifier!(u64, U64);
-reifier!(usize, USIZE);
+widths!(reifier);
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    use typenum::consts::{U0, U2};
+    use typenum::consts::{U0, U1, U2, U5};
+
+    use crate::Field;
+
+    // Every width must be reifiable both ways and carry bounded and
+    // checked constructors; a width missing any of them fails to
+    // build here.
+    macro_rules! coverage {
+        ($num_type:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<covers_ $num_type>]() {
+                    assert_eq!(<U5 as ReifyTo<$num_type>>::reify(), 5);
+                    assert_eq!(<$num_type as RegisterWidth>::from_unsigned::<U5>(), 5);
+                    assert_eq!(Reifier::<U5, $num_type>::reify(), 5);
+                    let b = Bounded::<$num_type, U0, U2>::checked::<U1>();
+                    assert_eq!(b.val, 1);
+                    let f = Field::<$num_type, U1, U0, U1, ()>::checked::<U1>();
+                    assert_eq!(f.val(), 1);
+                }
+            }
+        };
+    }
+
+    widths!(coverage);
 
     #[test]
     fn within_range() {
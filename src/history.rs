@@ -0,0 +1,98 @@
+//! A record of the most recent register modifications, kept when the
+//! `history` feature is enabled.
+//!
+//! Every `modify` of an `RW` register pushes the register's name
+//! along with its value before and after onto a ring buffer shared by
+//! all registers. The buffer holds the last `HISTORY_LEN` of them and
+//! can be inspected from a panic handler or a debugger after the fact.
+//!
+//! The buffer is guarded by an atomic flag rather than a lock, so a
+//! modification made while the buffer is being read or written (from
+//! an interrupt handler, say) is dropped rather than waited on.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// `HISTORY_LEN` is the number of modifications the history keeps.
+pub const HISTORY_LEN: usize = 32;
+
+/// A single modification of a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// The name of the register which was modified.
+    pub register: &'static str,
+    /// The register's bits before the modification, zero-extended even
+    /// for a signed register.
+    pub old: u128,
+    /// The register's bits after the modification.
+    pub new: u128,
+}
+
+/// A copy of the history, taken by `history()`.
+#[derive(Clone, Copy, Debug)]
+pub struct History {
+    records: [Option<Record>; HISTORY_LEN],
+    next: usize,
+}
+
+impl History {
+    const EMPTY: History = History {
+        records: [None; HISTORY_LEN],
+        next: 0,
+    };
+
+    /// `iter` walks the recorded modifications, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Record> {
+        let (newer, older) = self.records.split_at(self.next);
+        older.iter().chain(newer).flatten()
+    }
+}
+
+struct Ring {
+    busy: AtomicBool,
+    history: UnsafeCell<History>,
+}
+
+// Access to `history` is serialized by `busy`.
+unsafe impl Sync for Ring {}
+
+impl Ring {
+    fn with<T>(&self, f: impl FnOnce(&mut History) -> T) -> Option<T> {
+        if self
+            .busy
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return None;
+        }
+        let out = f(unsafe { &mut *self.history.get() });
+        self.busy.store(false, Ordering::Release);
+        Some(out)
+    }
+}
+
+static RING: Ring = Ring {
+    busy: AtomicBool::new(false),
+    history: UnsafeCell::new(History::EMPTY),
+};
+
+/// `record` pushes a modification onto the history, evicting the
+/// oldest one when it is full.
+#[doc(hidden)]
+pub fn record(register: &'static str, old: u128, new: u128) {
+    RING.with(|h| {
+        h.records[h.next] = Some(Record { register, old, new });
+        h.next = (h.next + 1) % HISTORY_LEN;
+    });
+}
+
+/// `history` returns a copy of the recorded modifications, or `None`
+/// if the history was busy being updated.
+pub fn history() -> Option<History> {
+    RING.with(|h| *h)
+}
+
+/// `clear` forgets every recorded modification.
+pub fn clear() {
+    RING.with(|h| *h = History::EMPTY);
+}
@@ -54,6 +54,9 @@
 //! the values of the field, nested within the field declaration with
 //! `[]`'s
 //!
+//! In place of its width and offset, a field may also be given as the
+//! inclusive range of bits it spans, e.g. `BITS(U4..U7)`.
+//!
 //! The code which this macro generates is a tree of nested modules where
 //! the root is a module called `$register_name`. Within `$register_name`,
 //! there will be the register itself, as `$register_name::Register`, as
@@ -73,6 +76,10 @@
 //!   Passing it to `modify` will set that field to its max value in the
 //!   register. This is useful particularly in the case of single-bit wide
 //!   fields.
+//! * `$register_name::$field_name::{OFFSET, MASK, WIDTH}`: the field's offset
+//!   and in-position mask as `Width`s, and its width in bits as a `u32`.
+//! * `$register_name::$field_name::extract_const`: a `const fn` decoding the
+//!   field's value from a whole register value.
 //! * `$register_name::$field_name::$enum_kvs`: constants mapping the enum like
 //!   field names to values.
 //!
@@ -118,10 +125,11 @@
 //! dereference that pointer and use the register API to access the
 //! registers in the block.
 //!
-//! You can then implement `Deref` and `DerefMut` for a type which holds
-//! onto the address of such a register block. This fills in the gaps for
+//! `RegBlock` holds onto the address of such a register block and
+//! implements `Deref` and `DerefMut` for it. This fills in the gaps for
 //! method lookup (during typechecking) so that you can ergonomically use
-//! this type to interact with the register block:
+//! it to interact with the register block. Constructing one is `unsafe`,
+//! as the address must really be that of the block:
 //!
 //! ```
 //! #[macro_use]
@@ -129,7 +137,7 @@
 //! #[macro_use]
 //! extern crate typenum;
 //!
-//! use core::ops::{Deref, DerefMut};
+//! use bounded_registers::RegBlock;
 //!
 //! register! {
 //!     UartRX,
@@ -187,32 +195,13 @@
 //!     control1: UartControl1::Register,
 //! }
 //!
-//! pub struct Regs {
-//!     addr: usize,
-//! }
-//!
-//! impl Deref for Regs {
-//!     type Target = UartBlock;
-//!
-//!     fn deref(&self) -> &UartBlock {
-//!         unsafe { &*(self.addr as *const UartBlock) }
-//!     }
-//! }
-//!
-//! impl DerefMut for Regs {
-//!     fn deref_mut(&mut self) -> &mut UartBlock {
-//!         unsafe { &mut *(self.addr as *mut UartBlock) }
-//!     }
-//! }
-//!
 //! fn main() {
 //!     let mut x = [0_u32; 33];
-//!     let mut regs = Regs {
-//!         // Some shenanigans to get at `x` as though it were a
-//!         // pointer. Normally you'd be given some address like
-//!         // `0xDEADBEEF` over which you'd instantiate a `Regs`.
-//!         addr: &mut x as *mut [u32; 33] as usize,
-//!     };
+//!     // Some shenanigans to get at `x` as though it were a pointer.
+//!     // Normally you'd be given some address like `0xDEADBEEF` over
+//!     // which you'd instantiate a `RegBlock`.
+//!     let mut regs: RegBlock<UartBlock> =
+//!         unsafe { RegBlock::new(&mut x as *mut [u32; 33] as usize) };
 //!
 //!     assert_eq!(regs.rx.read(), 0);
 //!     regs.control1
@@ -278,8 +267,9 @@
 //! // `Positioned` is a special trait that all fields implement, as
 //! // well as a type used as an accumulator when reading from or
 //! // writing to multiple fields. To use these functions with
-//! // multiple fields, join them together with `+`. An `Add`
-//! // implementation for fields has been provided for this purpose.
+//! // multiple fields, join them together with `+` (or `|`). `Add`
+//! // and `BitOr` implementations for fields have been provided for
+//! // this purpose.
 //!
 //! /// `matches_any` returns whether or not any of the given fields
 //! /// match those fields values inside the register.
@@ -312,14 +302,38 @@
 //! to construct a value at runtime which is known to not contravene
 //! it.
 #![no_std]
-#![feature(const_fn_trait_bound)]
+#![cfg_attr(test, recursion_limit = "256")]
 
 #[allow(unused_imports)]
 #[macro_use]
 extern crate typenum;
 
+#[cfg(feature = "mock")]
+extern crate alloc;
+
+pub mod access;
+#[macro_use]
 pub mod bounds;
+#[cfg(feature = "history")]
+pub mod history;
 pub mod macros;
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[doc(hidden)]
+pub use paste;
+
+#[cfg(feature = "critical-section")]
+#[doc(hidden)]
+pub use critical_section;
+
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+pub use defmt;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
 
 mod register;
 pub use crate::register::*;
@@ -11,6 +11,17 @@
 /// register. Optionally, one may also state enum-like key/value pairs for the
 /// values of the field, nested within the field declaration with `[]`'s
 ///
+/// In place of `WIDTH(..) OFFSET(..)`, a field may be given as the inclusive
+/// range of bits it spans, as datasheets tend to list them: `BITS(U4..U7)`
+/// is the same field as `WIDTH(U4) OFFSET(U4)`. A field given as a range
+/// of one bit gets no `bit_band`, though.
+///
+/// A field may be put behind `#[cfg(..)]`, e.g. for a field only some
+/// revisions of a chip have. A field which is configured out is left out
+/// of everything generated for the register, its masks and
+/// `field_descriptors` included, so fields behind mutually exclusive
+/// `cfg`s may overlap.
+///
 /// The code which this macro generates is a tree of nested modules where the
 /// root is a module called `$register_name`. Within `$register_name`, there
 /// will be the register itself, as `$register_name::Register`, as well as a
@@ -33,6 +44,260 @@
 /// * `$register_name::$field_name::$enum_kvs`: constants mapping the enum like
 ///   field names to values.
 ///
+/// The mode may be followed by the register's power-on reset value, e.g.
+/// `RESET(U5)`, which is then available as `Register::RESET_VALUE` and through
+/// `Register::reset()`, and each field's share of it as the field's `RESET`.
+/// Without it, the reset value is zero and fields have no `RESET`. A field
+/// whose share of the reset value lies below its `MIN` fails to compile.
+///
+/// Next may come the byte order the register is laid out in on the bus,
+/// `ENDIAN(BE)` or `ENDIAN(LE)`, for a peripheral whose byte order differs
+/// from the core's. Every access then swaps the register's bytes as
+/// needed, so that values are always handed to and from the register in
+/// the core's byte order, `new`'s included. Without it, the register is
+/// accessed in the core's byte order.
+///
+/// Then, `STRICT` has the register checked, at compile time, for bits which
+/// no field covers; see the compile-time checks below.
+///
+/// After that, one may optionally list extra code to generate with
+/// `Derive[...]`:
+///
+/// * `Accessors`: a getter (`reg.color()`) and, for writable registers, a
+///   bounds-checked setter (`reg.set_color(val)`) on `Register` for every
+///   field, named after the field in snake case. Enum-like fields also get
+///   a getter for the name of their value (`reg.color_value()`) and a
+///   setter which takes one (`reg.set_color_variant(Color::Value::Blue)`).
+///   Read-only and reserved fields get no setters.
+/// * `Clone` and `Copy`: implementations for `Register`, for snapshotting
+///   registers held in ordinary memory, such as ones captured for analysis
+///   on a host. Copying a live register copies its _value_ into ordinary
+///   memory, not its mapping; writes to the copy never reach the hardware.
+///   Only an explicit `.clone()` reads the register volatilely: the
+///   implicit copies `Copy` allows are plain memory reads, which the
+///   compiler may elide or reuse. `Copy` requires `Clone`.
+/// * `Debug`: a `core::fmt::Debug` implementation for `Register` which shows
+///   each field by name, along with the name of its value for enum-like
+///   fields, e.g. `Status { On: 0, Dead: 1, Color: Blue(2) }`.
+/// * `Display`: a `core::fmt::Display` implementation for `Register` which
+///   shows its bits, split at the fields' boundaries, followed by each
+///   field's value, e.g. `0b000_010_1_0 (On=0 Dead=1 Color=Blue(2))`.
+///
+/// A field may also declare how it is accessed after its offset, e.g.
+/// `Pending WIDTH(U1) OFFSET(U3) ACCESS(W1C)` for a write-one-to-clear flag.
+/// `modify` never writes back a write-one-to-clear field as it was read, only
+/// the value it is given, so passing its `Set` clears the flag. Fields which
+/// don't declare their access take on the register's mode.
+///
+/// A field declared `ACCESS(RO)` in a writable register, such as a status
+/// bit beside control bits, gets no `Set`, `Clear`, or setter, and is
+/// ignored by `Config`. Its `Field` belongs to `ReadOnly<Register>`, which
+/// every reader accepts but which isn't `Writable`, so handing it to
+/// `modify` fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Uart,
+///     u8,
+///     RW,
+///     Fields [
+///         Busy WIDTH(U1) OFFSET(U0) ACCESS(RO),
+///         Enable WIDTH(U1) OFFSET(U1)
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Uart::Register::new(0);
+///     reg.modify(Uart::Busy::Field::new(1).unwrap());
+/// }
+/// ```
+///
+/// Every read-modify-write, `modify_with` included, writes its bits back
+/// as they were read. A field may only narrow its register's mode, so a
+/// writable field in an `RO` register fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Status,
+///     u8,
+///     RO,
+///     Fields [
+///         Ready WIDTH(U1) OFFSET(U0),
+///         Pending WIDTH(U1) OFFSET(U1) ACCESS(W1C)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// A field declared `ACCESS(RESERVED)` marks bits the datasheet reserves.
+/// It is listed in `field_descriptors()` and has `Read`, but no `Set`,
+/// `Clear`, or setter, and its `Field` belongs to `Reserved<Register>`
+/// rather than the register, so it can't be written. `modify` leaves its
+/// bits as they were, and `Config` ignores its member. Handing it to
+/// `modify`, alone or joined to other fields, fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Control,
+///     u8,
+///     RW,
+///     Fields [
+///         Enable WIDTH(U1) OFFSET(U0),
+///         Reserved WIDTH(U3) OFFSET(U5) ACCESS(RESERVED)
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Control::Register::new(0);
+///     reg.modify(Control::Enable::Set + Control::Reserved::Read);
+/// }
+/// ```
+///
+/// With the `defmt` feature enabled, every register implements
+/// `defmt::Format`, showing its fields much like `Derive[Debug]` does.
+///
+/// With the `bitband` feature enabled, single-bit fields get a `bit_band`
+/// function returning the field's Cortex-M bit-band alias, whose
+/// `set_atomic` and `clear_atomic` change the bit in a single write. The
+/// alias follows the register's `ENDIAN`, if any, to the byte which holds
+/// the bit.
+///
+/// With the `serde` feature enabled, every readable register implements
+/// `serde::Serialize` as a struct of its fields, keyed by name, with
+/// enum-like fields given by the names of their values. A `ReadOnlyCopy`
+/// serializes, and deserializes, as its raw value.
+///
+/// With the `history` feature enabled, every `modify` of an `RW`
+/// register is recorded, along with the register's `NAME` and its
+/// value before and after, in the crate's `history` module.
+///
+/// With the `critical-section` feature enabled, `RW` registers also get a
+/// `modify_cs` which takes a `critical_section::CriticalSection` token, for
+/// read-modify-writes which must not be torn by an interrupt handler.
+///
+/// With the `cell` feature enabled, every register holds its value in a
+/// `core::cell::UnsafeCell`, which its volatile reads and writes go
+/// through, so that driver logic can be run under Miri against registers
+/// in ordinary memory. Registers are laid out and accessed on hardware
+/// just as they are without it, but can't derive `Copy`.
+///
+/// Registers may be `u8`, `u16`, `u32`, `u64`, `u128`, or `usize` wide, or
+/// signed: `i8`, `i16`, `i32`, or `i64`. A signed register's fields hold
+/// their raw bits, like any other's, and `get_signed` reads a field as a
+/// two's-complement number as wide as the field. No field may span the
+/// whole of a signed register. The
+/// type-level arithmetic behind the masks of very wide fields (64 bits and up,
+/// as on `u128` registers) recurses deeply, so the crate invoking `register!`
+/// may need to raise its limit with `#![recursion_limit = "256"]`.
+///
+/// A field may declare the smallest value it can hold after its offset, e.g.
+/// `Divisor WIDTH(U3) OFFSET(U0) MIN(U1)` for a divisor which must not be
+/// zero. Its `Field` then rejects smaller values, both at runtime and in
+/// `checked`, and there is no `Clear` for it:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// # use typenum::consts::U0;
+/// register! {
+///     Clock,
+///     u8,
+///     RW,
+///     Fields [
+///         Divisor WIDTH(U3) OFFSET(U0) MIN(U1)
+///     ]
+/// }
+///
+/// fn main() {
+///     let _ = Clock::Divisor::Field::checked::<U0>();
+/// }
+/// ```
+///
+/// Attributes given before the register's name, doc comments included, are
+/// applied to the generated `Register` struct. Marking it `#[must_use]`
+/// turns a register which is constructed and then dropped into a warning:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     #[must_use]
+///     Latch,
+///     u8,
+///     RW,
+///     Fields [
+///         Armed WIDTH(U1) OFFSET(U0)
+///     ]
+/// }
+///
+/// fn main() {
+///     // The register is built and immediately thrown away.
+///     Latch::Register::new(1);
+/// }
+/// ```
+///
+/// Alongside the register, `$register_name::Config` is a struct with one
+/// member per field, named after the field in snake case. Handing it to
+/// `modify` (or `apply_config`) reprograms every field at once.
+/// Readable registers likewise get `$register_name::Values`, whose members
+/// hold each field's value, and `read_fields`, which fills one in from a
+/// single read of the register. `from_fields` goes the other way, packing
+/// a `Values` into a new register once each value is found in bounds.
+/// Writable registers get `$register_name::build()`, which starts a
+/// `Builder`: fields are added to it by methods named after them in snake
+/// case, as in `Status::build().dead(Status::Dead::Set)`, and it can be
+/// handed to `modify` or turned into a raw value with `pack`.
+///
+/// A field's enum-like values may be given either as typenum constants or
+/// as integer literals, in whichever base the datasheet uses. A literal
+/// must be one typenum has a constant for: any number up to 1024, as well
+/// as larger powers of two, and those powers less one.
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Clock,
+///     u16,
+///     RW,
+///     Fields [
+///         Source WIDTH(U4) OFFSET(U0) [
+///             Internal = 0b0001,
+///             External = U2,
+///             Off = 0xF
+///         ],
+///         Divider WIDTH(U11) OFFSET(U4) [
+///             Max = 1024
+///         ]
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Clock::Register::new(0);
+///     reg.modify(Clock::Source::Off + Clock::Divider::Max);
+///     assert_eq!(reg.read(), 0x400F);
+/// }
+/// ```
+///
 /// An example register and its use is below:
 /// ```
 /// #[macro_use]
@@ -64,6 +329,249 @@
 ///     assert_eq!(reg.read(), 2);
 /// }
 /// ```
+///
+/// # Compile-time checks
+///
+/// Every field's mask and offset must be representable in the register's
+/// width; a field which would be silently truncated fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Narrow,
+///     u8,
+///     RW,
+///     Fields [
+///         // Bits 6 through 9 don't fit in a `u8`.
+///         TooFar WIDTH(U4) OFFSET(U6)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The same goes for a field whose offset was copied from a datasheet
+/// one row off:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Wide,
+///     u32,
+///     RW,
+///     Fields [
+///         Mode WIDTH(U2) OFFSET(U28),
+///         // Bits 30 through 33 don't fit in a `u32`.
+///         Prescale WIDTH(U4) OFFSET(U30)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Nor may two fields claim the same bit:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Tangled,
+///     u8,
+///     RW,
+///     Fields [
+///         Low WIDTH(U4) OFFSET(U0),
+///         // Bit 3 already belongs to `Low`.
+///         Mid WIDTH(U2) OFFSET(U3)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// A field must span at least one bit; one declared `WIDTH(U0)` is
+/// reported by name:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Control,
+///     u8,
+///     RW,
+///     Fields [
+///         Enable WIDTH(U1) OFFSET(U0),
+///         Empty WIDTH(U0) OFFSET(U1)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// An enum-like value must fit in its field:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Mode,
+///     u8,
+///     RW,
+///     Fields [
+///         Speed WIDTH(U3) OFFSET(U0) [
+///             Slow = U1,
+///             // A 3-bit field holds at most 7.
+///             Big = U9
+///         ]
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Zero is out of range for a field with a `MIN`, so a register with
+/// such a field can't be cleared as a whole, nor `overwrite`n, which
+/// writes the fields it isn't given as zero, and the field itself can't
+/// be `toggle`d, which may flip it to zero:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Clock,
+///     u8,
+///     RW,
+///     Fields [
+///         Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+///         Enable WIDTH(U1) OFFSET(U3)
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Clock::Register::new(1);
+///     reg.clear();
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Clock,
+///     u8,
+///     RW,
+///     Fields [
+///         Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+///         Enable WIDTH(U1) OFFSET(U3)
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Clock::Register::new(1);
+///     reg.overwrite(Clock::Enable::Set);
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Clock,
+///     u8,
+///     RW,
+///     Fields [
+///         Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+///         Enable WIDTH(U1) OFFSET(U3)
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut reg = Clock::Register::new(7);
+///     reg.toggle(Clock::Divisor::Read);
+/// }
+/// ```
+///
+/// A register declared `STRICT` must account for every one of its bits,
+/// so that a datasheet can't be transcribed only in part. Bits which are
+/// reserved are declared as fields with `ACCESS(RESERVED)`:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Control,
+///     u8,
+///     RW,
+///     STRICT,
+///     Fields [
+///         Enable WIDTH(U1) OFFSET(U0),
+///         // Bit 1 is neither a field nor reserved.
+///         Mode WIDTH(U2) OFFSET(U2),
+///         Reserved WIDTH(U4) OFFSET(U4) ACCESS(RESERVED)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// A register which spans fewer bits than its numeric type, such as a
+/// 24-bit register held in a `u32`, says so with `BITS(..)`. Its fields
+/// must then lie within those bits, and `STRICT` asks only for those bits
+/// to be accounted for:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Sample,
+///     u32,
+///     RO,
+///     BITS(U24),
+///     Fields [
+///         Value WIDTH(U24) OFFSET(U0),
+///         // Bit 24 lies beyond the register.
+///         Sign WIDTH(U1) OFFSET(U24)
+///     ]
+/// }
+/// # fn main() {}
+/// ```
+///
+/// And no two of a field's enum-like values may be the same, so that
+/// every value decodes to a single name:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Mode,
+///     u8,
+///     RW,
+///     Fields [
+///         Speed WIDTH(U3) OFFSET(U0) [
+///             Slow = U1,
+///             Low = U1
+///         ]
+///     ]
+/// }
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! register {
     {
@@ -71,7 +579,51 @@ macro_rules! register {
         $name:ident,
         $width:ty,
         $mode:ident,
-        Fields [$($fields:tt)*]
+        $(RESET($reset:ty),)?
+        $(ENDIAN($endian:ident),)?
+        $(BITS($bits:ident),)?
+        STRICT,
+        $($rest:tt)*
+    } => {
+        register! {
+            $(#[$attrs])*
+            $name,
+            $width,
+            $mode,
+            $(RESET($reset),)?
+            $(ENDIAN($endian),)?
+            $(BITS($bits),)?
+            $($rest)*
+        }
+
+        const _: () = assert!(
+            $name::Register::DEFINED_MASK == $name::Register::BITS_MASK,
+            concat!(
+                "register `",
+                stringify!($name),
+                "` is STRICT, but has bits which no field covers",
+            ),
+        );
+    };
+    {
+        @aliases { $($alias:ident = $alias_ty:ty;)* }
+        $(#[$attrs:meta])*
+        $name:ident,
+        $width:ty,
+        $mode:ident,
+        $(RESET($reset:ty),)?
+        $(ENDIAN($endian:ident),)?
+        $(BITS($bits:ident),)?
+        $(Derive[$($derive:ident),* $(,)?],)?
+        Fields [
+            $(
+                $(#[$field_attrs:meta])*
+                $field:ident WIDTH($field_width:ident) OFFSET($field_offset:ident)
+                $(MIN($min:ident))?
+                $(ACCESS($access:ident))?
+                $([ $($enums:tt)* ])?
+            ),* $(,)?
+        ]
     } => {
         #[allow(unused)]
         #[allow(non_snake_case)]
@@ -87,394 +639,4214 @@ macro_rules! register {
 
             type Width = $width;
 
+            /// The number of bits the register spans, as a type-level
+            /// number.
+            type Bits = register_bits!(@type $($bits)?);
+
+            $(type $alias = $alias_ty;)*
+
             #[repr(C)]
             $(#[$attrs])*
-            pub struct Register(Width);
+            #[doc = field_links!($($field),*)]
+            pub struct Register(storage!(@type));
 
-            mode!($mode);
+            storage!(@sync);
 
-            fields!($($fields)*);
+            impl $crate::Writable for Register {}
 
-        }
-    }
-}
+            impl $crate::RegisterMeta for Register {
+                type Width = Width;
 
-#[macro_export]
-#[doc(hidden)]
-macro_rules! fields {
-    {
-        $(#[$outer:meta])*
-        $name:ident WIDTH($width:ident) OFFSET($offset:ident) [ $($enums:tt)* ] $($rest:tt)*
-    } => {
-        #[allow(unused)]
-        #[allow(non_upper_case_globals)]
-        #[allow(non_snake_case)]
-        pub mod $name {
+                const NAME: &'static str = Register::NAME;
+                const BIT_WIDTH: u32 = Register::BIT_WIDTH;
+                const BITS: u32 = Register::BITS;
+                const DEFINED_MASK: Width = Register::DEFINED_MASK;
+            }
 
-            use super::*;
+            impl Register {
+                /// `to_bus` converts a value from the core's byte order to
+                /// the register's.
+                const fn to_bus(val: Width) -> Width {
+                    endian!(@to val $(, $endian)?)
+                }
 
-            type _Offset = $offset;
-            type _FieldWidth = $width;
+                /// `load` reads the register, in the core's byte order.
+                fn load(&self) -> Width {
+                    let val = unsafe { storage!(@read self.0) };
+                    endian!(@from val $(, $endian)?)
+                }
 
-            $(#[$outer])*
-            pub type Field = F<super::Width, op!(((U1 << $width) - U1) << $offset), $offset, op!((U1 << $width) - U1), Register>;
+                /// `store` writes `val`, given in the core's byte order, to
+                /// the register.
+                fn store(&mut self, val: Width) {
+                    unsafe { storage!(@write self.0, Register::to_bus(val)) };
+                }
+            }
 
-            /// In order to read a field, an instance of that field
-            /// must be given to have access to its mask and
-            /// offset. `Read` can be used as an argument to
-            /// `get_field` so one does not have to construct an
-            /// arbitrary one when doing a read.
-            pub const Read: Field = Field::checked::<U0>();
+            $crate::paste::paste! {
+                /// `Config` carries a value for every field in the
+                /// register. It is `Positioned`, so handing it to
+                /// `modify` reprograms all of the fields in a single
+                /// read-modify-write, leaving only the bits which no
+                /// field covers untouched.
+                pub struct Config {
+                    $(
+                        $(#[$field_attrs])*
+                        pub [<$field:snake>]: $field::Field,
+                    )*
+                }
 
+                impl Positioned for Config {
+                    type Width = Width;
 
-            /// A field whose value is `$field_max`. Passing it to
-            /// `modify` will set that field to its max value in the
-            /// register. This is useful particularly in the case of
-            /// single-bit wide fields.
-            pub const Set: Field = Field::checked::<op!((U1 << $width) - U1)>();
+                    fn mask(&self) -> Width {
+                        0 $(| reserved!(@config self.[<$field:snake>].mask() $(, $access)?))*
+                    }
 
-            /// A field whose value is zero. Passing it to `modify`
-            /// will clear that field in the register.
-            pub const Clear: Field = Read;
+                    fn in_position(&self) -> Width {
+                        0 $(| reserved!(@config self.[<$field:snake>].in_position() $(, $access)?))*
+                    }
+                }
+            }
 
-            /// Constants mapping the enum-like field names to values.
-            enums!($($enums)*);
-        }
+            impl Register {
+                /// `NAME` is the register's name, as given to `register!`.
+                pub const NAME: &'static str = stringify!($name);
 
-        fields!($($rest)*);
-    };
-    {
-        $(#[$outer:meta])*
-        $name:ident WIDTH($width:ident) OFFSET($offset:ident) $($rest:tt)*
-    } => {
-        #[allow(unused)]
-        #[allow(non_upper_case_globals)]
-        #[allow(non_snake_case)]
-        pub mod $name {
+                /// `RESET_VALUE` is the register's power-on reset value,
+                /// as given by its `RESET` clause, or zero otherwise.
+                pub const RESET_VALUE: Width = 0 $(| Reifier::<$reset, Width>::reify())?;
 
-            use super::*;
+                /// `reset` constructs a register holding its power-on
+                /// reset value.
+                pub fn reset() -> Self {
+                    Register::new(Register::RESET_VALUE)
+                }
 
-            $(#[$outer])*
-            pub type Field = F<super::Width, op!(((U1 << $width) - U1) << $offset), $offset, op!((U1 << $width) - U1), Register>;
+                /// `BIT_WIDTH` is the size of the register's numeric type
+                /// in bits.
+                pub const BIT_WIDTH: u32 = Width::BITS;
 
-            /// In order to read a field, an instance of that field
-            /// must be given to have access to its mask and
-            /// offset. `Read` can be used as an argument to
-            /// `get_field` so one does not have to construct an
-            /// arbitrary one when doing a read.
-            pub const Read: Field = Field::checked::<U0>();
+                /// `BITS` is the number of bits the register spans: those
+                /// of `Width`, unless fewer were given by its `BITS`
+                /// clause. Its fields must lie within them.
+                pub const BITS: u32 = register_bits!($($bits)?);
 
-            /// A field whose value is `$field_max`. Passing it to
-            /// `modify` will set that field to its max value in the
-            /// register. This is useful particularly in the case of
-            /// single-bit wide fields.
-            pub const Set: Field = Field::checked::<op!((U1 << $width) - U1)>();
+                /// `BITS_MASK` covers the bits the register spans.
+                pub const BITS_MASK: Width = if Register::BITS == Width::BITS {
+                    !0
+                } else {
+                    (1 << Register::BITS) - 1
+                };
 
-            /// A field whose value is zero. Passing it to `modify`
-            /// will clear that field in the register.
-            pub const Clear: Field = Read;
-        }
+                /// `DEFINED_MASK` covers every bit which belongs to one
+                /// of the register's fields: the union of their masks.
+                /// Writes to any other bit are caught by `modify` in debug
+                /// builds.
+                pub const DEFINED_MASK: Width = 0 $(
+                    | Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify()
+                )*;
 
-        fields!($($rest)*);
-    };
-    (, $($rest:tt)*) => (fields!($($rest)*););
-    () => ()
-}
+                /// `W1C_MASK` covers every bit which belongs to a
+                /// write-one-to-clear field. Those bits are never written
+                /// back as they were read, lest a latched flag be cleared
+                /// by accident.
+                pub const W1C_MASK: Width = 0 $(
+                    | if <$field::Access as $crate::access::Access>::W1C {
+                        Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify()
+                    } else {
+                        0
+                    }
+                )*;
 
-#[macro_export]
-#[doc(hidden)]
-macro_rules! enums {
-    {
-        $(
+                /// `RO_MASK` covers every bit which belongs to a field
+                /// which may only be read. Every read-modify-write writes
+                /// them back as they were read, whatever it was given.
+                pub const RO_MASK: Width = 0 $(
+                    | if <$field::Access as $crate::access::Access>::WRITABLE {
+                        0
+                    } else {
+                        Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify()
+                    }
+                )*;
 
-            $(#[$outer:meta])*
-            $name:ident = $val:ident
-        ),*
-    } => {
-        $(
-            $(#[$outer])*
-            pub const $name: Field = Field::checked::<$val>();
-        )*
-    }
-}
+                /// `ALL_FIELDS` lists the name and mask of every field in
+                /// the register, in declaration order.
+                pub const ALL_FIELDS: &'static [(&'static str, Width)] = &[$((
+                    stringify!($field),
+                    Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify(),
+                )),*];
 
-#[macro_export]
-#[doc(hidden)]
-macro_rules! mode {
-    (RO) => {
-        impl Register {
-            /// `new` constructs a read-only register around the given
-            /// value.
+                /// `field_descriptors` describes every field in the
+                /// register, in declaration order, for tooling which walks
+                /// a register map generically.
+                pub fn field_descriptors() -> &'static [$crate::FieldDescriptor<Width>] {
+                    const DESCRIPTORS: &[$crate::FieldDescriptor<Width>] = &[$(
+                        $crate::FieldDescriptor {
+                            name: stringify!($field),
+                            offset: <$field_offset as Unsigned>::U32,
+                            width: <$field_width as Unsigned>::U32,
+                            mask: Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify(),
+                        }
+                    ),*];
+                    DESCRIPTORS
+                }
+
+                /// `try_new` is `new`, but first checks that `init` is a
+                /// value the register's fields allow: every field within
+                /// its bounds, every reserved field zero, and no bit set
+                /// outside of the fields. It is meant for registers built
+                /// in ordinary memory, as in tests and simulations.
+                pub fn try_new(init: Width) -> Result<Self, $crate::InitError<Width>> {
+                    $(
+                        let raw = $field::extract_const(init);
+                        if reserved!(@nonzero raw $(, $access)?) {
+                            return Err($crate::InitError::Reserved(stringify!($field)));
+                        }
+                        if let Err(err) = $field::Field::try_new(raw) {
+                            return Err($crate::InitError::OutOfBounds(stringify!($field), err));
+                        }
+                    )*
+                    let undefined = init & !Register::DEFINED_MASK;
+                    if undefined != 0 {
+                        return Err($crate::InitError::Undefined(undefined));
+                    }
+                    Ok(Register::new(init))
+                }
+
+                /// `field_at` returns the name of the field covering the
+                /// bit at `offset`, or `None` if no field covers it.
+                pub fn field_at(offset: u32) -> Option<&'static str> {
+                    Register::field_descriptors()
+                        .iter()
+                        .find(|f| offset >= f.offset && offset - f.offset < f.width)
+                        .map(|f| f.name)
+                }
+
+                /// `changed_fields` returns the names of the fields whose
+                /// values differ between the snapshots `a` and `b`.
+                pub fn changed_fields<'a>(
+                    a: &'a $crate::ReadOnlyCopy<Width, Register>,
+                    b: &'a $crate::ReadOnlyCopy<Width, Register>,
+                ) -> impl Iterator<Item = &'static str> + 'a {
+                    let diff = a.diff(b);
+                    Register::ALL_FIELDS
+                        .iter()
+                        .filter(move |(_, mask)| diff & mask != 0)
+                        .map(|(name, _)| *name)
+                }
+            }
+
+            impl core::convert::TryFrom<&[u8]> for Register {
+                type Error = core::array::TryFromSliceError;
+
+                /// Assembles a register from its byte representation on
+                /// the bus: in its `ENDIAN` byte order if it has one, and
+                /// little-endian otherwise. The slice must be exactly as
+                /// long as `Width`, otherwise an error is returned.
+                fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                    core::convert::TryInto::try_into(bytes)
+                        .map(|bytes| Register::new(endian!(@bytes bytes $(, $endian)?)))
+                }
+            }
+
+            mode!($mode);
+
+            lower!(@clear_all $mode $($($min)?)*);
+
+            lower!(@overwrite $mode $($($min)?)*);
+
+            fields!(
+                $mode;
+                [$($reset)?];
+                $(
+                    $(#[$field_attrs])*
+                    $field WIDTH($field_width) OFFSET($field_offset)
+                    $(MIN($min))?
+                    $(ACCESS($access))?
+                    $([ $($enums)* ])?
+                ),*
+            );
+
+            derives!(
+                $name $mode [$($($derive),*)?]
+                { $($field $field_width $field_offset [$($access)?] $([$($enums)*])?),* }
+            );
+
+            defmt_format!(
+                $name $mode
+                { $($field $field_width $field_offset [$($access)?] $([$($enums)*])?),* }
+            );
+
+            serialize_fields!(
+                $name $mode
+                { $($field $field_width $field_offset [$($access)?] $([$($enums)*])?),* }
+            );
+
+            values!(
+                $mode
+                { $($field $field_width $field_offset [$($access)?] $([$($enums)*])?),* }
+            );
+
+            builder!(
+                $mode
+                { $($field $field_width $field_offset [$($access)?] $([$($enums)*])?),* }
+            );
+
+            const _: () = assert!(
+                Register::BITS <= Width::BITS,
+                concat!(
+                    "register `",
+                    stringify!($name),
+                    "` spans more bits than its width holds",
+                ),
+            );
+
+            $(
+                const _: () = assert!(
+                    <$field_offset as Unsigned>::U32 + <$field_width as Unsigned>::U32
+                        <= Register::BITS,
+                    concat!(
+                        "the mask of field `",
+                        stringify!($field),
+                        "` does not fit within the register's width",
+                    ),
+                );
+
+                const _: () = assert!(
+                    Width::MIN == 0 || <$field_width as Unsigned>::U32 < Width::BITS,
+                    concat!(
+                        "field `",
+                        stringify!($field),
+                        "` spans the whole of a signed register, whose values can't \
+                         be bounded",
+                    ),
+                );
+            )*
+
+            const _: () = {
+                let mut seen: Width = 0;
+                $(
+                    let mask = Reifier::<op!(((U1 << $field_width) - U1) << $field_offset), Width>::reify();
+                    assert!(
+                        seen & mask == 0,
+                        concat!(
+                            "field `",
+                            stringify!($field),
+                            "` overlaps a field declared before it",
+                        ),
+                    );
+                    seen |= mask;
+                )*
+                let _ = seen;
+            };
+        }
+    };
+    // Every register is first put through `normalize_fields!`, which
+    // resolves its fields' `cfg`s and rewrites `BITS(..)` fields to
+    // `WIDTH(..) OFFSET(..)`, before it is generated.
+    { $($tokens:tt)* } => {
+        normalize_fields! { @start $($tokens)* }
+    };
+}
+
+/// `normalize_fields` walks a register's fields and hands the register
+/// back to `register!` with only those fields which are compiled in.
+///
+/// A field behind one or more `#[cfg(..)]`s forks the walk in two: one
+/// half, itself behind the field's `cfg`s, keeps the field, and the other,
+/// behind their negation, drops it. Only one of the halves survives, so
+/// every item generated from the fields, from the register's masks to its
+/// field descriptors, sees the same set of them.
+///
+/// Every field given as an inclusive range of bits, `BITS(U4..U7)`, is
+/// rewritten to one given by its width and offset, `WIDTH(..) OFFSET(U4)`.
+/// The width is an alias, declared in the register's module, of `U7 - U4
+/// + 1`, since `op!` can only be handed names.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! normalize_fields {
+    {
+        @start
+        $(#[$attrs:meta])*
+        $name:ident,
+        $width:ty,
+        $mode:ident,
+        $(RESET($reset:ty),)?
+        $(ENDIAN($endian:ident),)?
+        $(BITS($bits:ident),)?
+        $(Derive[$($derive:ident),* $(,)?],)?
+        Fields [ $($fields:tt)* ]
+    } => {
+        normalize_fields! {
+            @munch
+            [
+                $(#[$attrs])*
+                $name,
+                $width,
+                $mode,
+                $(RESET($reset),)?
+                $(ENDIAN($endian),)?
+                $(BITS($bits),)?
+                $(Derive[$($derive),*],)?
+            ]
+            []
+            []
+            []
+            []
+            $($fields)*
+        }
+    };
+    // The field's docs are gathered up to its next `cfg` at once, and any
+    // other attribute one at a time, keeping the walk short.
+    {
+        @munch [$($header:tt)*] [$($aliases:tt)*] [$($done:tt)*]
+        [$($field_attrs:tt)*] [$($cfgs:tt)*]
+        $(#[doc = $doc:literal])*
+        #[cfg($($cfg:tt)*)]
+        $($rest:tt)*
+    } => {
+        normalize_fields! {
+            @munch [$($header)*] [$($aliases)*] [$($done)*]
+            [$($field_attrs)* $(#[doc = $doc])*] [$($cfgs)* [$($cfg)*]]
+            $($rest)*
+        }
+    };
+    {
+        @munch [$($header:tt)*] [$($aliases:tt)*] [$($done:tt)*]
+        [$($field_attrs:tt)*] [$([$($cfg:tt)*])*]
+        $(#[doc = $doc:literal])*
+        $field:ident BITS($low:ident .. $high:ident)
+        $(MIN($min:ident))?
+        $(ACCESS($access:ident))?
+        $([ $($enums:tt)* ])?
+        $(, $($rest:tt)*)?
+    } => {
+        #[cfg(all($($($cfg)*),*))]
+        normalize_fields! {
+            @munch [$($header)*]
+            [
+                $($aliases)*
+                [<__ $field Width>] = typenum::Add1<typenum::Diff<$high, $low>>;
+            ]
+            [
+                $($done)*
+                $($field_attrs)*
+                $(#[doc = $doc])*
+                $field WIDTH([<__ $field Width>]) OFFSET($low)
+                $(MIN($min))?
+                $(ACCESS($access))?
+                $([ $($enums)* ])?,
+            ]
+            [] []
+            $($($rest)*)?
+        }
+
+        #[cfg(not(all($($($cfg)*),*)))]
+        normalize_fields! {
+            @munch [$($header)*] [$($aliases)*] [$($done)*] [] []
+            $($($rest)*)?
+        }
+    };
+    {
+        @munch [$($header:tt)*] [$($aliases:tt)*] [$($done:tt)*]
+        [$($field_attrs:tt)*] [$([$($cfg:tt)*])*]
+        $(#[doc = $doc:literal])*
+        $field:ident WIDTH($field_width:ident) OFFSET($field_offset:ident)
+        $(MIN($min:ident))?
+        $(ACCESS($access:ident))?
+        $([ $($enums:tt)* ])?
+        $(, $($rest:tt)*)?
+    } => {
+        #[cfg(all($($($cfg)*),*))]
+        normalize_fields! {
+            @munch [$($header)*] [$($aliases)*]
+            [
+                $($done)*
+                $($field_attrs)*
+                $(#[doc = $doc])*
+                $field WIDTH($field_width) OFFSET($field_offset)
+                $(MIN($min))?
+                $(ACCESS($access))?
+                $([ $($enums)* ])?,
+            ]
+            [] []
+            $($($rest)*)?
+        }
+
+        #[cfg(not(all($($($cfg)*),*)))]
+        normalize_fields! {
+            @munch [$($header)*] [$($aliases)*] [$($done)*] [] []
+            $($($rest)*)?
+        }
+    };
+    {
+        @munch [$($header:tt)*] [$($aliases:tt)*] [$($done:tt)*]
+        [$($field_attrs:tt)*] [$($cfgs:tt)*]
+        #[$($attr:tt)*]
+        $($rest:tt)*
+    } => {
+        normalize_fields! {
+            @munch [$($header)*] [$($aliases)*] [$($done)*]
+            [$($field_attrs)* #[$($attr)*]] [$($cfgs)*]
+            $($rest)*
+        }
+    };
+    { @munch [$($header:tt)*] [$($aliases:tt)*] [$($done:tt)*] [] [] } => {
+        $crate::paste::paste! {
+            register! {
+                @aliases { $($aliases)* }
+                $($header)*
+                Fields [ $($done)* ]
+            }
+        }
+    };
+}
+
+/// The `bank!` macro declares registers which share one address, the
+/// register found there depending on the value of a bank-select field in
+/// another register, such as a 16550 UART's `DLAB` bit. The expected input
+/// for the macro is as follows:
+/// 1. The bank's name.
+/// 2. Its numeric type, which must be that of each of its registers.
+/// 3. The bank-select field, as `SELECT($register_name::$field_name)`. Its
+///    register must be `RW`.
+/// 4. The banked registers, each declared by `register!` in the same module,
+///    beginning with `Banks [` and then a closing `]` at the end. Each is
+///    given as a name for the bank, the value which selects it, and the
+///    register: `Divisor = U1 => Dll`. The value may be written as a
+///    literal, as with enum-like field values.
+///
+/// It generates a module, `$bank_name`, holding `$bank_name::Bank`, which
+/// takes the place of the registers in a register block, and a guard for
+/// each bank. The guard dereferences to the bank's register, and is only
+/// handed out in exchange for a borrow of the bank-select register,
+/// either by `select_$bank`, which selects the bank, or by `$bank`, which
+/// returns `None` if it isn't selected. While the guard lives, the bank
+/// can't be switched out from underneath it.
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// register! {
+///     Lcr,
+///     u8,
+///     RW,
+///     Fields [
+///         WordLength WIDTH(U2) OFFSET(U0),
+///         Dlab WIDTH(U1) OFFSET(U7)
+///     ]
+/// }
+///
+/// register! {
+///     Rbr,
+///     u8,
+///     RO,
+///     Fields [
+///         Data WIDTH(U8) OFFSET(U0)
+///     ]
+/// }
+///
+/// register! {
+///     Dll,
+///     u8,
+///     RW,
+///     Fields [
+///         Divisor WIDTH(U8) OFFSET(U0)
+///     ]
+/// }
+///
+/// bank! {
+///     Uart0,
+///     u8,
+///     SELECT(Lcr::Dlab),
+///     Banks [
+///         Receive = U0 => Rbr,
+///         Divisor = U1 => Dll
+///     ]
+/// }
+///
+/// fn main() {
+///     let mut lcr = Lcr::Register::new(0);
+///     let mut bank = Uart0::Bank::new(0);
+///
+///     let mut dll = bank.select_divisor(&mut lcr);
+///     dll.modify(Dll::Divisor::Field::new(12).unwrap());
+///     assert_eq!(dll.read(), 12);
+///
+///     assert!(bank.receive(&mut lcr).is_none());
+///     assert_eq!(bank.select_receive(&mut lcr).read(), 12);
+///     assert_eq!(lcr.read(), 0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! bank {
+    {
+        $(#[$attrs:meta])*
+        $name:ident,
+        $width:ty,
+        SELECT($select:ident :: $select_field:ident),
+        Banks [
+            $(
+                $(#[$bank_attrs:meta])*
+                $bank:ident = $val:tt => $reg:ident
+            ),* $(,)?
+        ]
+    } => {
+        #[allow(unused)]
+        #[allow(non_snake_case)]
+        pub mod $name {
+            use super::*;
+            use typenum::consts::*;
+
+            use $crate::bounds::Reifier;
+
+            type Width = $width;
+
+            /// `Bank` is the address the banked registers share. In a
+            /// register block, it takes their place.
+            #[repr(C)]
+            $(#[$attrs])*
+            pub struct Bank(Width);
+
+            impl Bank {
+                /// `new` constructs a bank holding `init`, in whichever
+                /// register is selected.
+                pub fn new(init: Width) -> Self {
+                    Bank(init)
+                }
+            }
+
+            $crate::paste::paste! {
+                $(
+                    $(#[$bank_attrs])*
+                    #[doc = concat!(
+                        "A guard over the bank's `", stringify!($reg), "` register, held while \
+                         the bank is selected. It dereferences to the register.",
+                    )]
+                    pub struct $bank<'a> {
+                        reg: &'a mut $reg::Register,
+                        _select: &'a mut $select::Register,
+                    }
+
+                    impl<'a> core::ops::Deref for $bank<'a> {
+                        type Target = $reg::Register;
+
+                        fn deref(&self) -> &$reg::Register {
+                            self.reg
+                        }
+                    }
+
+                    impl<'a> core::ops::DerefMut for $bank<'a> {
+                        fn deref_mut(&mut self) -> &mut $reg::Register {
+                            self.reg
+                        }
+                    }
+
+                    impl Bank {
+                        #[doc = concat!(
+                            "`select_", stringify!([<$bank:snake>]), "` selects the bank by \
+                             writing to `", stringify!($select), "`, and returns a guard over \
+                             its register.",
+                        )]
+                        pub fn [<select_ $bank:snake>]<'a>(
+                            &'a mut self,
+                            select: &'a mut $select::Register,
+                        ) -> $bank<'a> {
+                            select.modify($select::$select_field::Field::checked::<enum_value!($val)>());
+                            $bank {
+                                reg: unsafe { &mut *(self as *mut Bank as *mut $reg::Register) },
+                                _select: select,
+                            }
+                        }
+
+                        #[doc = concat!(
+                            "`", stringify!([<$bank:snake>]), "` returns a guard over the bank's \
+                             register if `", stringify!($select), "` already selects it, or \
+                             `None` otherwise.",
+                        )]
+                        pub fn [<$bank:snake>]<'a>(
+                            &'a mut self,
+                            select: &'a mut $select::Register,
+                        ) -> Option<$bank<'a>> {
+                            if select.get_field($select::$select_field::Read)?.val()
+                                != Reifier::<enum_value!($val), Width>::reify()
+                            {
+                                return None;
+                            }
+                            Some($bank {
+                                reg: unsafe { &mut *(self as *mut Bank as *mut $reg::Register) },
+                                _select: select,
+                            })
+                        }
+                    }
+                )*
+            }
+
+            $(
+                const _: () = assert!(
+                    core::mem::size_of::<$reg::Register>() == core::mem::size_of::<Bank>(),
+                    concat!(
+                        "register `",
+                        stringify!($reg),
+                        "` is not as wide as the bank it is in",
+                    ),
+                );
+            )*
+
+            const _: () = {
+                let vals: &[Width] = &[$(
+                    Reifier::<enum_value!($val), Width>::reify()
+                ),*];
+                let mut i = 0;
+                while i < vals.len() {
+                    let mut j = i + 1;
+                    while j < vals.len() {
+                        assert!(vals[i] != vals[j], "two banks are selected by the same value");
+                        j += 1;
+                    }
+                    i += 1;
+                }
+            };
+        }
+    };
+}
+
+/// `endian` converts a register's value between the core's byte order
+/// and the one the register was declared with by `ENDIAN(..)`, if any.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! endian {
+    (@from $val:expr) => {
+        $val
+    };
+    (@from $val:expr, BE) => {
+        Width::from_be($val)
+    };
+    (@from $val:expr, LE) => {
+        Width::from_le($val)
+    };
+    (@to $val:expr) => {
+        $val
+    };
+    (@to $val:expr, BE) => {
+        Width::to_be($val)
+    };
+    (@to $val:expr, LE) => {
+        Width::to_le($val)
+    };
+    (@bytes $bytes:expr) => {
+        Width::from_le_bytes($bytes)
+    };
+    (@bytes $bytes:expr, BE) => {
+        Width::from_be_bytes($bytes)
+    };
+    (@bytes $bytes:expr, LE) => {
+        Width::from_le_bytes($bytes)
+    };
+}
+
+/// `register_bits` gives the number of bits a register spans, as
+/// declared by its `BITS(..)` clause, if any.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! register_bits {
+    () => {
+        Width::BITS
+    };
+    ($bits:ident) => {
+        <$bits as typenum::Unsigned>::U32
+    };
+    (@type) => {
+        <Width as $crate::bounds::RegisterWidth>::Bits
+    };
+    (@type $bits:ident) => {
+        $bits
+    };
+}
+
+/// `field_links` renders the `# Fields` section of a register's docs,
+/// an intra-doc link from the `Register` to each of its field modules.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! field_links {
+    ($($field:ident),*) => {
+        concat!(
+            "\n# Fields\n",
+            $("\n* [`", stringify!($field), "`](", stringify!($field), ")",)*
+            "\n"
+        )
+    };
+}
+
+/// `nonzero_width` rejects a field declared `WIDTH(U0)`, which could
+/// hold no value, with an error naming it, ahead of the less helpful
+/// ones from the bounds on `Field`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! nonzero_width {
+    ($name:ident U0) => {
+        compile_error!(concat!(
+            "field `",
+            stringify!($name),
+            "` has a width of zero"
+        ));
+    };
+    ($name:ident $width:ident) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! fields {
+    {
+        $mode:ident;
+        $reset:tt;
+        $(
+            $(#[$outer:meta])*
+            $name:ident WIDTH($width:ident) OFFSET($offset:ident)
+            $(MIN($min:ident))?
+            $(ACCESS($access:ident))?
+            $([ $($enums:tt)* ])?
+        ),*
+    } => {
+        $(
+            nonzero_width!($name $width);
+            access!(@check $name $mode $($access)?);
+
+            #[allow(unused)]
+            #[allow(non_upper_case_globals)]
+            #[allow(non_snake_case)]
+            pub mod $name {
+
+                use super::*;
+
+                type _Offset = $offset;
+                type _FieldWidth = $width;
+                type _Mask = op!(((U1 << $width) - U1) << $offset);
+                type _Lower = lower!(@bound $($min)?);
+                type _Register = reserved!(@register $($access)?);
+
+                $(#[$outer])*
+                pub type Field = F<super::Width, op!(((U1 << $width) - U1) << $offset), $offset, op!((U1 << $width) - U1), _Register, _Lower>;
+
+                /// `Access` is the marker type describing how the field
+                /// may be accessed. It is the register's mode unless the
+                /// field declares its own with `ACCESS(...)`.
+                pub type Access = access!(@marker $mode $($access)?);
+
+                /// In order to read a field, an instance of that field
+                /// must be given to have access to its mask and
+                /// offset. `Read` can be used as an argument to
+                /// `get_field` so one does not have to construct an
+                /// arbitrary one when doing a read.
+                pub const Read: Field = Field::checked::<_Lower>();
+
+                reserved!(@writes $mode [$($min)?] $width $offset $($access)?);
+
+                /// `OFFSET` is the bit position of the field's least
+                /// significant bit within the register.
+                pub const OFFSET: super::Width = Reifier::<$offset, super::Width>::reify();
+
+                /// `MASK` covers the field's bits, in position within the
+                /// register.
+                pub const MASK: super::Width = Reifier::<op!(((U1 << $width) - U1) << $offset), super::Width>::reify();
+
+                /// `WIDTH` is the number of bits the field spans.
+                pub const WIDTH: u32 = <$width as Unsigned>::U32;
+
+                /// `extract_const` decodes the field's value from a whole
+                /// register value. Unlike `get_field`, it is a `const fn`,
+                /// so it can be used to check packed values at compile
+                /// time.
+                pub const fn extract_const(word: super::Width) -> super::Width {
+                    (word >> OFFSET) & Reifier::<op!((U1 << $width) - U1), super::Width>::reify()
+                }
+
+                /// `values` yields the field holding each value it can
+                /// hold, from its lower bound up to its upper bound.
+                pub fn values() -> impl Iterator<Item = Field> {
+                    (Reifier::<_Lower, super::Width>::reify()
+                        ..=Reifier::<op!((U1 << $width) - U1), super::Width>::reify())
+                        .filter_map(Field::new)
+                }
+
+                field_reset!($width $offset $reset);
+
+                /// `SubField<SW, SO>` is the field covering the `SW` bits
+                /// at offset `SO` _within_ this field. Its mask and offset
+                /// are relative to the register, like any other field's.
+                pub type SubField<SW, SO> = F<
+                    super::Width,
+                    typenum::Shleft<typenum::Diff<typenum::Shleft<U1, SW>, U1>, typenum::Sum<$offset, SO>>,
+                    typenum::Sum<$offset, SO>,
+                    typenum::Diff<typenum::Shleft<U1, SW>, U1>,
+                    _Register,
+                >;
+
+                /// `sub_field` returns a zero-valued `SubField<SW, SO>`,
+                /// which, like `Read`, can be handed to `get_field`. The
+                /// sub-range must lie within this field, otherwise the
+                /// program will fail to typecheck.
+                pub const fn sub_field<SW, SO>() -> SubField<SW, SO>
+                where
+                    SW: Unsigned + core::ops::Add<SO>,
+                    SO: Unsigned,
+                    typenum::Sum<SW, SO>: typenum::IsLessOrEqual<$width, Output = True>,
+                    U1: core::ops::Shl<SW>,
+                    typenum::Shleft<U1, SW>: core::ops::Sub<U1>,
+                    typenum::Diff<typenum::Shleft<U1, SW>, U1>: Unsigned
+                        + IsGreater<U0, Output = True>
+                        + core::ops::Shl<typenum::Sum<$offset, SO>>,
+                    $offset: core::ops::Add<SO>,
+                    typenum::Sum<$offset, SO>: Unsigned,
+                    typenum::Shleft<typenum::Diff<typenum::Shleft<U1, SW>, U1>, typenum::Sum<$offset, SO>>: Unsigned,
+                    U0: typenum::IsLessOrEqual<typenum::Diff<typenum::Shleft<U1, SW>, U1>, Output = True>,
+                {
+                    SubField::<SW, SO>::zero()
+                }
+
+                $(
+                    /// Constants mapping the enum-like field names to values.
+                    enums!($($enums)*);
+                )?
+            }
+        )*
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! field_reset {
+    ($width:ident $offset:ident []) => {};
+    ($width:ident $offset:ident [$reset:ty]) => {
+        /// The field's value when the register is at its reset value.
+        pub const RESET: Field = Field::checked::<
+            typenum::And<typenum::Shright<$reset, $offset>, op!((U1 << $width) - U1)>,
+        >();
+    };
+}
+
+/// `reserved` sets a field declared `ACCESS(RESERVED)` apart from the
+/// others: it belongs to `Reserved<Register>`, and none of the ways of
+/// writing a field are generated for it.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! reserved {
+    (@register RESERVED) => {
+        $crate::Reserved<Register>
+    };
+    (@register RO) => {
+        $crate::ReadOnly<Register>
+    };
+    (@register $($access:ident)?) => {
+        Register
+    };
+    (@writes $mode:ident [$($min:ident)?] $width:ident $offset:ident RESERVED) => {};
+    (@writes $mode:ident [$($min:ident)?] $width:ident $offset:ident RO) => {};
+    (@writes $mode:ident [$($min:ident)?] $width:ident $offset:ident $($access:ident)?) => {
+        #[doc = access!(@set_doc $mode $($access)?)]
+        pub const Set: Field = Field::checked::<op!((U1 << $width) - U1)>();
+
+        lower!(@clear $($min)?);
+
+        bit_band!($width $offset);
+    };
+    (@nonzero $raw:ident, RESERVED) => {
+        $raw != 0
+    };
+    (@nonzero $raw:ident $(, $access:ident)?) => {
+        false
+    };
+    (@config $e:expr, RESERVED) => {
+        0
+    };
+    (@config $e:expr, RO) => {
+        0
+    };
+    (@config $e:expr $(, $access:ident)?) => {
+        $e
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! lower {
+    (@bound) => {
+        U0
+    };
+    (@bound $min:ident) => {
+        $min
+    };
+    // A field with a lower bound can't be cleared, so it has no `Clear`.
+    (@clear) => {
+        /// A field whose value is zero. Passing it to `modify`
+        /// will clear that field in the register.
+        pub const Clear: Field = Read;
+    };
+    (@clear $min:ident) => {};
+    // Nor can a register with such a field be cleared as a whole, so
+    // only read-write registers whose fields all lack a `MIN` get `clear`.
+    (@clear_all RW) => {
+        impl Register {
+            /// `clear` writes zero to the whole register. Zero is in
+            /// range for every one of its fields, so unlike `write` it
+            /// is safe.
+            pub fn clear(&mut self) {
+                self.store(0);
+            }
+        }
+    };
+    (@clear_all $mode:ident $($min:ident)*) => {};
+    // `overwrite` writes the fields it isn't given as zero, so it too is
+    // left out of registers with a field which has a `MIN`.
+    (@overwrite RW) => {
+        impl Register {
+            /// `overwrite` writes the whole register from one or more
+            /// fields, joined by `+`, without reading it first. Every
+            /// field is already bounded, so unlike `write` it is safe;
+            /// the fields which are not given are written as zero, as
+            /// are the bits of read-only fields.
+            pub fn overwrite<V: Positioned<Width = Width>>(&mut self, val: V) {
+                self.store(val.in_position() & val.mask() & !Register::RO_MASK);
+            }
+        }
+    };
+    (@overwrite WO) => {
+        impl Register {
+            /// `overwrite` writes the whole register from one or more
+            /// fields, joined by `+`. Every field is already bounded, so
+            /// unlike `write` it is safe; the fields which are not given
+            /// are written as zero. For a write-only register this is
+            /// the same as `modify`.
+            pub fn overwrite<V: Positioned<Width = Width>>(&mut self, val: V) {
+                self.modify(val);
+            }
+        }
+    };
+    (@overwrite $mode:ident $($min:ident)*) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! access {
+    (@marker $mode:ident) => {
+        $crate::access::$mode
+    };
+    // A reserved field may only be read.
+    (@marker $mode:ident RESERVED) => {
+        $crate::access::RO
+    };
+    (@marker $mode:ident $access:ident) => {
+        $crate::access::$access
+    };
+    // A field may only narrow the register's mode: a register with any
+    // writable field must be writable, and one which can't be read can't
+    // have a field which may only be read.
+    (@check $field:ident RO RO) => {};
+    (@check $field:ident RO RESERVED) => {};
+    (@check $field:ident RO $access:ident) => {
+        compile_error!(concat!(
+            "field `",
+            stringify!($field),
+            "` may be written, but its register is `RO`; declare the register \
+             `RW` and its read-only fields `ACCESS(RO)`",
+        ));
+    };
+    (@check $field:ident WO RO) => {
+        compile_error!(concat!(
+            "field `",
+            stringify!($field),
+            "` may only be read, but its register is `WO`",
+        ));
+    };
+    (@check $field:ident $mode:ident $($access:ident)?) => {};
+    (@set_doc $mode:ident W1C) => {
+        "A field whose value is `$field_max`. The field is write-one-to-clear, so
+passing `Set` to `modify` _clears_ the flag latched in the register, while
+writing zero leaves it be."
+    };
+    (@set_doc $mode:ident $($access:ident)?) => {
+        "A field whose value is `$field_max`. Passing it to `modify` will set that
+field to its max value in the register. This is useful particularly in the
+case of single-bit wide fields."
+    };
+}
+
+/// `enum_value` gives the type-level number an enum-like value was
+/// declared as, be it a typenum constant, `U15`, or a literal, `0xF`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! enum_value {
+    ($val:ident) => {
+        $val
+    };
+    ($val:literal) => {
+        typenum::U<$val>
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! enums {
+    {
+        $(
+
+            $(#[$outer:meta])*
+            $name:ident = $val:tt
+        ),*
+    } => {
+        $(
+            $(#[$outer])*
+            pub const $name: Field = Field::checked::<enum_value!($val)>();
+        )*
+
+        const _: () = {
+            let vals: &[Width] = &[$(Reifier::<enum_value!($val), Width>::reify()),*];
+            let mut i = 0;
+            while i < vals.len() {
+                let mut j = i + 1;
+                while j < vals.len() {
+                    assert!(vals[i] != vals[j], "two of the field's enum-like values are the same");
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+
+        /// `Value` enumerates the field's named values.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Value {
+            $(
+                $(#[$outer])*
+                $name,
+            )*
+        }
+
+        impl Value {
+            /// `name` returns the value's name, as declared.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Value::$name => stringify!($name),)*
+                }
+            }
+
+            /// `from_field` returns the named value `f` holds, or
+            /// `None` if its value has no name.
+            pub fn from_field(f: &Field) -> Option<Value> {
+                try_decode(f)
+            }
+        }
+
+        /// `variants` yields the field holding each of its named
+        /// values, in the order they were declared.
+        pub fn variants() -> impl Iterator<Item = Field> {
+            IntoIterator::into_iter([$($name),*])
+        }
+
+        /// `try_decode` returns the named value `f` holds, or `None` if
+        /// its value has no name, e.g. when it is reserved.
+        pub fn try_decode(f: &Field) -> Option<Value> {
+            <Register as $crate::Decode<Width, _Mask, _Offset>>::decode(f.val())
+        }
+
+        /// `decode` returns the named value of the field's raw value
+        /// `val`, or `None` if it has no name. Unlike `try_decode`, it
+        /// may be used in `const` contexts, such as building lookup
+        /// tables at compile time.
+        pub const fn decode(val: Width) -> Option<Value> {
+            $(
+                if val == Reifier::<enum_value!($val), Width>::reify() {
+                    return Some(Value::$name);
+                }
+            )*
+            None
+        }
+
+        /// `name_of` returns the name of the field's raw value `val`, as
+        /// declared, or `None` if it has no name.
+        pub const fn name_of(val: Width) -> Option<&'static str> {
+            match decode(val) {
+                Some(v) => Some(v.name()),
+                None => None,
+            }
+        }
+
+        impl core::convert::TryFrom<Width> for Value {
+            /// The raw value, which has no name.
+            type Error = Width;
+
+            /// Names a raw value of the field, such as one received from
+            /// outside of the register, failing if it is not one of the
+            /// field's legal encodings.
+            fn try_from(val: Width) -> Result<Self, Width> {
+                decode(val).ok_or(val)
+            }
+        }
+
+        impl From<Value> for Width {
+            fn from(val: Value) -> Width {
+                match val {
+                    $(Value::$name => Reifier::<enum_value!($val), Width>::reify(),)*
+                }
+            }
+        }
+
+        impl From<Value> for Field {
+            fn from(val: Value) -> Field {
+                match val {
+                    $(Value::$name => $name,)*
+                }
+            }
+        }
+
+        impl $crate::Decode<Width, _Mask, _Offset> for Register {
+            type Value = Value;
+
+            fn decode(val: Width) -> Option<Value> {
+                decode(val)
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! derives {
+    ($name:ident $mode:ident [$($derive:ident),*] $fields:tt) => {
+        $(derives!(@$derive $name $mode $fields);)*
+    };
+    (@Accessors $name:ident RO $fields:tt) => {
+        accessors!(get $fields);
+    };
+    (@Accessors $name:ident WO $fields:tt) => {
+        accessors!(set $fields);
+    };
+    (@Accessors $name:ident RW $fields:tt) => {
+        accessors!(get $fields);
+        accessors!(set $fields);
+    };
+    (@Clone $name:ident $mode:ident $fields:tt) => {
+        // An explicit `clone` takes the value with a volatile read, but
+        // with `Copy` also derived, implicit copies are plain bitwise
+        // ones and may see whatever the compiler last saw.
+        #[allow(clippy::non_canonical_clone_impl)]
+        impl Clone for Register {
+            fn clone(&self) -> Self {
+                Register(storage!(@new unsafe { storage!(@read self.0) }))
+            }
+        }
+    };
+    (@Copy $name:ident $mode:ident $fields:tt) => {
+        impl Copy for Register {}
+    };
+    (@Debug $name:ident WO $fields:tt) => {
+        impl core::fmt::Debug for Register {
+            /// A write-only register can't be read, so none of its
+            /// fields are shown.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+    (@Debug $name:ident $mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        impl core::fmt::Debug for Register {
+            /// Shows the value of each field, read from the register
+            /// all at once. The values of enum-like fields are shown
+            /// along with their name, when they have one.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let val = self.load();
+                f.debug_struct(stringify!($name))
+                    $(.field(
+                        stringify!($field),
+                        &field_value!(val, $field, $width, $offset $([$($enums)*])?),
+                    ))*
+                    .finish()
+            }
+        }
+    };
+    (@Display $name:ident WO $fields:tt) => {
+        impl core::fmt::Display for Register {
+            /// A write-only register can't be read, so nothing but its
+            /// name is shown.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!(stringify!($name), " (write-only)"))
+            }
+        }
+    };
+    (@Display $name:ident $mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        impl core::fmt::Display for Register {
+            /// Shows the register's bits, most significant first, with an
+            /// `_` wherever a field begins or ends, and then the value of
+            /// each field, all from a single read of the register.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let val = self.load();
+                let fields = Register::field_descriptors();
+                f.write_str("0b")?;
+                for bit in (0..Register::BITS).rev() {
+                    f.write_str(if (val >> bit) & 1 == 0 { "0" } else { "1" })?;
+                    if bit > 0 && fields.iter().any(|d| d.offset == bit || d.offset + d.width == bit) {
+                        f.write_str("_")?;
+                    }
+                }
+                f.write_str(" (")?;
+                let mut sep = "";
+                $(
+                    write!(
+                        f,
+                        "{}{}={:?}",
+                        sep,
+                        stringify!($field),
+                        field_value!(val, $field, $width, $offset $([$($enums)*])?),
+                    )?;
+                    sep = " ";
+                )*
+                let _ = sep;
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+/// `values` emits, for readable registers, the `Values` struct which
+/// holds every field's value and the `read_fields` method which fills
+/// one in from a single read.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! values {
+    (WO $fields:tt) => {};
+    ($mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        $crate::paste::paste! {
+            /// `Values` holds the value of every field in the register,
+            /// as read all at once by `read_fields`. Members are named
+            /// after their fields in snake case.
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct Values {
+                $(
+                    #[doc = concat!("The value of the [`", stringify!($field), "`](", stringify!($field), ") field.")]
+                    pub [<$field:snake>]: Width,
+                )*
+            }
+
+            impl Register {
+                /// `read_fields` reads the register once and returns the
+                /// value of each of its fields, rather than reading it
+                /// again for every field as `get_field` does.
+                pub fn read_fields(&self) -> Values {
+                    let val = self.load();
+                    Values {
+                        $([<$field:snake>]: field_value!(val, $field, $width, $offset),)*
+                    }
+                }
+
+                /// `from_fields` is the reverse of `read_fields`: it packs
+                /// the value of each field into a new register, or returns
+                /// the first field whose value its bounds rule out. Like
+                /// `try_new`, it wants reserved fields to be zero.
+                pub fn from_fields(values: Values) -> Result<Self, $crate::InitError<Width>> {
+                    let mut word: Width = 0;
+                    $(
+                        let f = $field::Field::try_new(values.[<$field:snake>])
+                            .map_err(|err| $crate::InitError::OutOfBounds(stringify!($field), err))?;
+                        word |= f.val() << $field::OFFSET;
+                    )*
+                    Register::try_new(word)
+                }
+            }
+        }
+    };
+}
+
+/// `builder` emits, for writable registers, the `Builder` which joins
+/// fields one method call at a time, and `build` which starts one.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! builder {
+    (RO $fields:tt) => {};
+    ($mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        /// `Builder` joins fields as `+` does, but one method call, named
+        /// after the field in snake case, at a time, which reads better
+        /// over a long run of fields or when some are only given under a
+        /// condition. It is `Positioned`, so it may be handed to `modify`
+        /// as it is.
+        #[derive(Clone, Copy, Debug)]
+        #[must_use]
+        pub struct Builder($crate::FieldDisj<Width>);
+
+        /// `build` starts a `Builder` which holds no fields.
+        pub fn build() -> Builder {
+            Builder($crate::FieldDisj::empty())
+        }
+
+        impl Builder {
+            $(builder!(@field $field $access);)*
+
+            /// `pack` returns the fields given so far, in position, as a
+            /// raw value. Bits which no given field covers are zero.
+            pub fn pack(self) -> Width {
+                self.0.in_position()
+            }
+        }
+
+        impl Positioned for Builder {
+            type Width = Width;
+
+            fn mask(&self) -> Width {
+                self.0.mask()
+            }
+
+            fn in_position(&self) -> Width {
+                self.0.in_position()
+            }
+        }
+    };
+    // Reserved and read-only fields can't be written, so they can't be
+    // given to a builder either.
+    (@field $field:ident [RESERVED]) => {};
+    (@field $field:ident [RO]) => {};
+    (@field $field:ident [$($access:ident)?]) => {
+        $crate::paste::paste! {
+            #[doc = concat!(
+                "`", stringify!([<$field:snake>]), "` adds `f`, a value of the [`",
+                stringify!($field), "`](", stringify!($field), ") field.",
+            )]
+            pub fn [<$field:snake>](self, f: $field::Field) -> Self {
+                Builder(self.0 + f)
+            }
+        }
+    };
+}
+
+/// `field_value` extracts a field's value from the whole register's
+/// value `val`. For enum-like fields, it is paired with its name.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! field_value {
+    ($val:expr, $field:ident, $width:ident, $offset:ident) => {
+        ($val >> Reifier::<$offset, Width>::reify())
+            & Reifier::<op!((U1 << $width) - U1), Width>::reify()
+    };
+    ($val:expr, $field:ident, $width:ident, $offset:ident [$($enums:tt)*]) => {{
+        let raw = field_value!($val, $field, $width, $offset);
+        $crate::NamedValue($field::Field::new(raw).and_then(|f| f.decode()), raw)
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! accessors {
+    (get { $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),* }) => {
+        $crate::paste::paste! {
+            impl Register {
+                $(
+                    #[doc = concat!(
+                        "`", stringify!([<$field:snake>]), "` reads the value of the [`",
+                        stringify!($field), "`](", stringify!($field), ") field.",
+                    )]
+                    pub fn [<$field:snake>](&self) -> Width {
+                        field_value!(self.load(), $field, $width, $offset)
+                    }
+
+                    $(accessors!(@named $field [$($enums)*]);)?
+                )*
+            }
+        }
+    };
+    // Enum-like fields also get a getter for the name of their value.
+    (@named $field:ident [$($enums:tt)*]) => {
+        $crate::paste::paste! {
+            #[doc = concat!(
+                "`", stringify!([<$field:snake _value>]), "` reads the [`",
+                stringify!($field), "`](", stringify!($field), ") field and returns the ",
+                "name of its value, or `None` if it has no name.",
+            )]
+            pub fn [<$field:snake _value>](&self) -> Option<$field::Value> {
+                $field::decode(self.[<$field:snake>]())
+            }
+        }
+    };
+    (set { $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),* }) => {
+        impl Register {
+            $(accessors!(@set $field $access $([$($enums)*])?);)*
+        }
+    };
+    // A reserved field can't be written, so it has no setter.
+    // Neither can a read-only one.
+    (@set $field:ident [RESERVED] $($enums:tt)?) => {};
+    (@set $field:ident [RO] $($enums:tt)?) => {};
+    (@set $field:ident [$($access:ident)?] $($enums:tt)?) => {
+        $crate::paste::paste! {
+            #[doc = concat!(
+                "`set_", stringify!([<$field:snake>]), "` sets the [`",
+                stringify!($field), "`](", stringify!($field), ") field to `val`, ",
+                "leaving the others as they were. If `val` does not fit ",
+                "the field, the register is left untouched and the reason ",
+                "is returned.",
+            )]
+            pub fn [<set_ $field:snake>](
+                &mut self,
+                val: Width,
+            ) -> Result<(), $crate::FieldError<Width>> {
+                $field::Field::try_new(val).map(|f| self.modify(f))
+            }
+
+            $(accessors!(@set_variant $field $enums);)?
+        }
+    };
+    // Enum-like fields also get a setter which takes the name of a
+    // value, and so can't fail.
+    (@set_variant $field:ident [$($enums:tt)*]) => {
+        $crate::paste::paste! {
+            #[doc = concat!(
+                "`set_", stringify!([<$field:snake _variant>]), "` sets the [`",
+                stringify!($field), "`](", stringify!($field), ") field to the named ",
+                "value `val`, leaving the others as they were.",
+            )]
+            pub fn [<set_ $field:snake _variant>](&mut self, val: $field::Value) {
+                self.modify($field::Field::from(val))
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! mode {
+    (RO) => {
+        impl Register {
+            /// `new` constructs a read-only register around the given
+            /// value.
+            pub fn new(init: Width) -> Self {
+                Register(storage!(@new Register::to_bus(init)))
+            }
+
+            /// `get_field` takes a field and sets the value of that
+            /// field to its value in the register.
+            pub fn get_field<M, O, U, L, Own>(
+                &self,
+                f: F<Width, M, O, U, Own, L>,
+            ) -> Option<F<Width, M, O, U, Own, L>>
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned
+                    + IsGreater<U0, Output = True>
+                    + IsGreater<L, Output = True>
+                    + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+                L: ReifyTo<Width>,
+            {
+                f.set((self.load() >> O::reify()) & U::reify())
+            }
+
+            /// `slice` reads the `SW` bits at offset `SO` in the register,
+            /// whether or not they make up a declared field, as a value
+            /// bounded by the largest `SW` bits can hold. A slice which
+            /// doesn't fit in the register fails to typecheck.
+            pub fn slice<SW, SO>(
+                &self,
+            ) -> $crate::bounds::Bounded<Width, U0, <SW as $crate::bounds::Span>::Max>
+            where
+                SW: Unsigned
+                    + $crate::bounds::Span
+                    + core::ops::Add<SO>
+                    + typenum::IsLessOrEqual<
+                        <Width as $crate::bounds::RegisterWidth>::BoundedBits,
+                        Output = True,
+                    >,
+                SO: Unsigned + ReifyTo<Width>,
+                typenum::Sum<SW, SO>: typenum::IsLessOrEqual<Bits, Output = True>,
+                <SW as $crate::bounds::Span>::Max:
+                    Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+            {
+                let val = (self.load() >> SO::reify())
+                    & <<SW as $crate::bounds::Span>::Max as ReifyTo<Width>>::reify();
+                $crate::bounds::Bounded::new(val).expect("a masked value lies within its mask")
+            }
+
+            /// `read` returns the current state of the register as a `Width`.
+            pub fn read(&self) -> Width {
+                self.load()
+            }
+
+            /// `read_ordered` is `read` followed by a memory fence of the
+            /// given ordering, so that no later memory access is made
+            /// before the register has been read. It is a fence around a
+            /// plain volatile read, not an atomic access. As with
+            /// `core::sync::atomic::fence`, `Ordering::Relaxed` panics.
+            pub fn read_ordered(&self, order: core::sync::atomic::Ordering) -> Width {
+                let val = self.load();
+                core::sync::atomic::fence(order);
+                val
+            }
+
+            /// `extract` pulls the state of a register out into a wrapped
+            /// read-only register. Every query on a register reads it
+            /// anew, so prefer `extract` when making several: it reads
+            /// the register once, and the queries on the copy all agree.
+            pub fn extract(&self) -> $crate::ReadOnlyCopy<Width, Register> {
+                $crate::ReadOnlyCopy(self.load(), PhantomData)
+            }
+
+            /// `is_set` takes a field and returns true if that field's value
+            /// is equal to its upper bound or not. This is of particular use
+            /// in single-bit fields.
+            pub fn is_set<M, O, U, L, Own>(&self, f: F<Width, M, O, U, Own, L>) -> bool
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                ((self.load() >> O::reify()) & U::reify()) == U::reify()
+            }
+
+            /// `is_clear` takes a field and returns whether or not all of
+            /// that field's bits are zero, the dual of `is_set`. For a
+            /// field with a lower bound, it still tests the bits against
+            /// zero, not against the bound.
+            pub fn is_clear<M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> bool
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned,
+            {
+                (self.load() & M::reify()) == 0
+            }
+
+            /// `flag` reads a single-bit field as a `Flag`, which
+            /// dereferences to whether or not the bit is set.
+            pub fn flag<M, O, Own>(&self, _: F<Width, M, O, U1, Own>) -> $crate::Flag
+            where
+                Own: $crate::BelongsTo<Register>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned,
+            {
+                $crate::Flag(self.load() & M::reify() != 0)
+            }
+
+            /// `get_enum` reads a field which declares named values and
+            /// returns the name of the value it holds, or `None` if that
+            /// value has no name.
+            pub fn get_enum<M, O, U, L, Own>(
+                &self,
+                _: F<Width, M, O, U, Own, L>,
+            ) -> Option<<Register as $crate::Decode<Width, M, O>>::Value>
+            where
+                Own: $crate::BelongsTo<Register>,
+                Register: $crate::Decode<Width, M, O>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                <Register as $crate::Decode<Width, M, O>>::decode(
+                    (self.load() >> O::reify()) & U::reify(),
+                )
+            }
+
+            /// `read_variant` is `get_enum`, but a value with no name is
+            /// an error carrying the raw value, so that an encoding the
+            /// hardware isn't documented to produce can't pass unnoticed.
+            pub fn read_variant<M, O, U, L, Own>(
+                &self,
+                _: F<Width, M, O, U, Own, L>,
+            ) -> Result<
+                <Register as $crate::Decode<Width, M, O>>::Value,
+                $crate::UnknownEncoding<Width>,
+            >
+            where
+                Own: $crate::BelongsTo<Register>,
+                Register: $crate::Decode<Width, M, O>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                let raw = (self.load() >> O::reify()) & U::reify();
+                <Register as $crate::Decode<Width, M, O>>::decode(raw)
+                    .ok_or($crate::UnknownEncoding { raw })
+            }
+
+            /// `get_signed` reads a field as a two's-complement number as
+            /// wide as the field, sign-extending it to the register's
+            /// `Width`, so a 4-bit field holding `0b1110` reads as `-2`
+            /// on a signed register. On an unsigned register, it reads
+            /// the field's value as it is.
+            pub fn get_signed<M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> Width
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True>,
+                M: Unsigned,
+                O: Unsigned,
+            {
+                let width = U::U128.count_ones();
+                (self.load() << (Width::BITS - O::U32 - width)) >> (Width::BITS - width)
+            }
+
+            /// `field_as` reads a field and converts its value into `T`,
+            /// such as a domain type which implements `From<Width>`.
+            pub fn field_as<T, M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> T
+            where
+                Own: $crate::BelongsTo<Register>,
+                T: From<Width>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                T::from((self.load() >> O::reify()) & U::reify())
+            }
+
+            /// `matches_any` returns whether or not any of the given fields
+            /// match those fields values inside the register.
+            pub fn matches_any<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) != 0
+            }
+
+            /// `matches_all` returns whether or not all of the given fields
+            /// match those fields values inside the register.
+            pub fn matches_all<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) == val.in_position()
+            }
+
+            /// `matches_none` returns whether or not none of the given
+            /// fields' bits are set inside the register.
+            pub fn matches_none<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) == 0
+            }
+
+            /// `is_any_field_set` returns whether or not any of the
+            /// register's fields holds a non-zero value.
+            pub fn is_any_field_set(&self) -> bool {
+                (self.load() & Register::DEFINED_MASK) != 0
+            }
+        }
+
+        impl $crate::Readable for Register {
+            type Width = Width;
+            type Register = Register;
+
+            fn read(&self) -> Width {
+                Register::read(self)
+            }
+        }
+    };
+    (WO) => {
+        impl Register {
+            /// `new` constructs a write-only register around the
+            /// given pointer.
+            pub fn new(init: Width) -> Self {
+                Register(storage!(@new Register::to_bus(init)))
+            }
+
+            /// `modify` takes one or more fields, joined by `+`, and
+            /// writes them to the register. A write-only register can't
+            /// be read back to merge with, so the fields which are not
+            /// given are written as zero.
+            ///
+            /// In debug builds, it panics if `val` covers bits which no
+            /// field does, as a `DynField` meant for another register
+            /// might.
+            pub fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
+                debug_assert!(
+                    val.mask() | Register::DEFINED_MASK == Register::DEFINED_MASK,
+                    "modify covers bits outside of the register's fields",
+                );
+                self.store(val.in_position() & val.mask());
+            }
+
+            /// `write_field` writes a single field to the register. It is
+            /// `modify` restricted to one field, so the value is bounded by
+            /// that field's type alone; like `modify`, it writes the
+            /// register's other fields as zero.
+            pub fn write_field<M, O, U, L>(&mut self, f: F<Width, M, O, U, Register, L>)
+            where
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+                L: ReifyTo<Width>,
+            {
+                self.modify(f);
+            }
+
+            /// `apply_config` programs every field in the register from
+            /// the given `Config` with a single write. A write-only
+            /// register can't be read back to merge with, so this
+            /// overwrites the _whole_ register: bits which no field
+            /// covers are written as zero.
+            pub fn apply_config(&mut self, cfg: Config) {
+                self.store(cfg.in_position());
+            }
+
+            /// `write` sets the value of the whole register to the
+            /// given `Width` value.
+            ///
+            /// # Safety
+            ///
+            /// Bypasses all assurances by writing a raw value directly to the register.
+            pub unsafe fn write(&mut self, val: Width) {
+                self.store(val);
+            }
+
+            /// `write_ordered` is `write` preceded by a memory fence of
+            /// the given ordering, so that every earlier memory access
+            /// is made before the register is written. It is a fence
+            /// around a plain volatile write, not an atomic access. As
+            /// with `core::sync::atomic::fence`, `Ordering::Relaxed`
+            /// panics.
+            ///
+            /// # Safety
+            ///
+            /// Bypasses all assurances by writing a raw value directly to the register.
+            pub unsafe fn write_ordered(
+                &mut self,
+                val: Width,
+                order: core::sync::atomic::Ordering,
+            ) {
+                core::sync::atomic::fence(order);
+                self.store(val);
+            }
+        }
+
+        impl $crate::Modify for Register {
+            type Width = Width;
+
+            fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
+                Register::modify(self, val);
+            }
+        }
+    };
+    (RW) => {
+        impl Register {
+            /// `new` constructs a read-write register around the
+            /// given pointer.
             pub fn new(init: Width) -> Self {
-                Register(init)
+                Register(storage!(@new Register::to_bus(init)))
+            }
+
+            /// `get_field` takes a field and sets the value of that
+            /// field to its value in the register.
+            pub fn get_field<M, O, U, L, Own>(
+                &self,
+                f: F<Width, M, O, U, Own, L>,
+            ) -> Option<F<Width, M, O, U, Own, L>>
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned
+                    + IsGreater<U0, Output = True>
+                    + IsGreater<L, Output = True>
+                    + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+                L: ReifyTo<Width>,
+            {
+                f.set((self.load() >> O::reify()) & U::reify())
+            }
+
+            /// `slice` reads the `SW` bits at offset `SO` in the register,
+            /// whether or not they make up a declared field, as a value
+            /// bounded by the largest `SW` bits can hold. A slice which
+            /// doesn't fit in the register fails to typecheck.
+            pub fn slice<SW, SO>(
+                &self,
+            ) -> $crate::bounds::Bounded<Width, U0, <SW as $crate::bounds::Span>::Max>
+            where
+                SW: Unsigned
+                    + $crate::bounds::Span
+                    + core::ops::Add<SO>
+                    + typenum::IsLessOrEqual<
+                        <Width as $crate::bounds::RegisterWidth>::BoundedBits,
+                        Output = True,
+                    >,
+                SO: Unsigned + ReifyTo<Width>,
+                typenum::Sum<SW, SO>: typenum::IsLessOrEqual<Bits, Output = True>,
+                <SW as $crate::bounds::Span>::Max:
+                    Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+            {
+                let val = (self.load() >> SO::reify())
+                    & <<SW as $crate::bounds::Span>::Max as ReifyTo<Width>>::reify();
+                $crate::bounds::Bounded::new(val).expect("a masked value lies within its mask")
+            }
+
+            /// `read` returns the current state of the register as a `Width`.
+            pub fn read(&self) -> Width {
+                self.load()
+            }
+
+            /// `read_ordered` is `read` followed by a memory fence of the
+            /// given ordering, so that no later memory access is made
+            /// before the register has been read. It is a fence around a
+            /// plain volatile read, not an atomic access. As with
+            /// `core::sync::atomic::fence`, `Ordering::Relaxed` panics.
+            pub fn read_ordered(&self, order: core::sync::atomic::Ordering) -> Width {
+                let val = self.load();
+                core::sync::atomic::fence(order);
+                val
+            }
+
+            /// `extract` pulls the state of a register out into a wrapped
+            /// read-only register. Every query on a register reads it
+            /// anew, so prefer `extract` when making several: it reads
+            /// the register once, and the queries on the copy all agree.
+            pub fn extract(&self) -> $crate::ReadOnlyCopy<Width, Register> {
+                $crate::ReadOnlyCopy(self.load(), PhantomData)
+            }
+
+            /// `is_set` takes a field and returns true if that field's value
+            /// is equal to its upper bound or not. This is of particular use
+            /// in single-bit fields.
+            pub fn is_set<M, O, U, L, Own>(&self, f: F<Width, M, O, U, Own, L>) -> bool
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                ((self.load() >> O::reify()) & U::reify()) == U::reify()
+            }
+
+            /// `is_clear` takes a field and returns whether or not all of
+            /// that field's bits are zero, the dual of `is_set`. For a
+            /// field with a lower bound, it still tests the bits against
+            /// zero, not against the bound.
+            pub fn is_clear<M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> bool
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned,
+            {
+                (self.load() & M::reify()) == 0
+            }
+
+            /// `flag` reads a single-bit field as a `Flag`, which
+            /// dereferences to whether or not the bit is set.
+            pub fn flag<M, O, Own>(&self, _: F<Width, M, O, U1, Own>) -> $crate::Flag
+            where
+                Own: $crate::BelongsTo<Register>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned,
+            {
+                $crate::Flag(self.load() & M::reify() != 0)
+            }
+
+            /// `get_enum` reads a field which declares named values and
+            /// returns the name of the value it holds, or `None` if that
+            /// value has no name.
+            pub fn get_enum<M, O, U, L, Own>(
+                &self,
+                _: F<Width, M, O, U, Own, L>,
+            ) -> Option<<Register as $crate::Decode<Width, M, O>>::Value>
+            where
+                Own: $crate::BelongsTo<Register>,
+                Register: $crate::Decode<Width, M, O>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                <Register as $crate::Decode<Width, M, O>>::decode(
+                    (self.load() >> O::reify()) & U::reify(),
+                )
+            }
+
+            /// `read_variant` is `get_enum`, but a value with no name is
+            /// an error carrying the raw value, so that an encoding the
+            /// hardware isn't documented to produce can't pass unnoticed.
+            pub fn read_variant<M, O, U, L, Own>(
+                &self,
+                _: F<Width, M, O, U, Own, L>,
+            ) -> Result<
+                <Register as $crate::Decode<Width, M, O>>::Value,
+                $crate::UnknownEncoding<Width>,
+            >
+            where
+                Own: $crate::BelongsTo<Register>,
+                Register: $crate::Decode<Width, M, O>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                let raw = (self.load() >> O::reify()) & U::reify();
+                <Register as $crate::Decode<Width, M, O>>::decode(raw)
+                    .ok_or($crate::UnknownEncoding { raw })
+            }
+
+            /// `get_signed` reads a field as a two's-complement number as
+            /// wide as the field, sign-extending it to the register's
+            /// `Width`, so a 4-bit field holding `0b1110` reads as `-2`
+            /// on a signed register. On an unsigned register, it reads
+            /// the field's value as it is.
+            pub fn get_signed<M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> Width
+            where
+                Own: $crate::BelongsTo<Register>,
+                U: Unsigned + IsGreater<U0, Output = True>,
+                M: Unsigned,
+                O: Unsigned,
+            {
+                let width = U::U128.count_ones();
+                (self.load() << (Width::BITS - O::U32 - width)) >> (Width::BITS - width)
+            }
+
+            /// `field_as` reads a field and converts its value into `T`,
+            /// such as a domain type which implements `From<Width>`.
+            pub fn field_as<T, M, O, U, L, Own>(&self, _: F<Width, M, O, U, Own, L>) -> T
+            where
+                Own: $crate::BelongsTo<Register>,
+                T: From<Width>,
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+            {
+                T::from((self.load() >> O::reify()) & U::reify())
+            }
+
+            /// `matches_any` returns whether or not any of the given fields
+            /// match those fields values inside the register.
+            pub fn matches_any<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) != 0
+            }
+
+            /// `matches_all` returns whether or not all of the given fields
+            /// match those fields values inside the register.
+            pub fn matches_all<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) == val.in_position()
+            }
+
+            /// `matches_none` returns whether or not none of the given
+            /// fields' bits are set inside the register.
+            pub fn matches_none<V: Positioned<Width = Width>>(&self, val: V) -> bool {
+                (val.in_position() & self.load()) == 0
+            }
+
+            /// `is_any_field_set` returns whether or not any of the
+            /// register's fields holds a non-zero value.
+            pub fn is_any_field_set(&self) -> bool {
+                (self.load() & Register::DEFINED_MASK) != 0
+            }
+
+            /// `modify` takes one or more fields, joined by `+`, and
+            /// sets those fields in the register, leaving the others
+            /// as they were. Write-one-to-clear fields which are not
+            /// given are written as zero, which leaves them be.
+            ///
+            /// In debug builds, it panics if `val` covers bits which no
+            /// field does, as a `DynField` meant for another register
+            /// might.
+            pub fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
+                self.replace(val);
+            }
+
+            /// `replace` is `modify`, but returns the whole register's
+            /// value from before the write, as read by the same
+            /// read-modify-write. Like `modify`, it is _not_ atomic.
+            pub fn replace<V: Positioned<Width = Width>>(&mut self, val: V) -> Width {
+                debug_assert!(
+                    val.mask() | Register::DEFINED_MASK == Register::DEFINED_MASK,
+                    "modify covers bits outside of the register's fields",
+                );
+                let mask = val.mask() & !Register::RO_MASK;
+                let old = self.load();
+                let new = (old & !mask & !Register::W1C_MASK) | (val.in_position() & mask);
+                self.store(new);
+                record_modify!(Register::NAME, old, new);
+                old
+            }
+
+            /// `modify_with` reads the register once, hands its value to
+            /// `f`, and writes back what `f` returns in a single write.
+            /// This saves the repeated reads of making a decision with
+            /// the matchers and then calling `modify`, but it is _not_
+            /// atomic: an interrupt handler which writes the register
+            /// between the read and the write will have its write lost.
+            ///
+            /// Bits of read-only fields are written back as they were
+            /// read, and write-one-to-clear fields are written as zero,
+            /// whatever `f` returns for them.
+            ///
+            /// # Safety
+            ///
+            /// Like `write`, the value `f` returns is written raw: it
+            /// isn't checked against the fields' bounds, so it may leave
+            /// a field outside of them, a field with a `MIN` below it,
+            /// or a reserved bit set.
+            pub unsafe fn modify_with<F: FnOnce(Width) -> Width>(&mut self, f: F) {
+                let old = self.load();
+                let new = f(old) & !Register::W1C_MASK;
+                let new = (new & !Register::RO_MASK) | (old & Register::RO_MASK);
+                self.store(new);
+                record_modify!(Register::NAME, old, new);
+            }
+
+            /// `toggle` flips every bit of the given field in the
+            /// register, leaving the others as they were. Flipping may
+            /// land on zero, so it only takes fields without a `MIN`.
+            pub fn toggle<M, O, U>(&mut self, _: F<Width, M, O, U, Register, U0>)
+            where
+                U: Unsigned + IsGreater<U0, Output = True>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned,
+            {
+                let old = self.load();
+                let new = (old & !Register::W1C_MASK) ^ M::reify();
+                self.store(new);
+                record_modify!(Register::NAME, old, new);
+            }
+
+            /// `increment` adds `by` to the given field's value in the
+            /// register, leaving the other fields as they were, and
+            /// returns whether the count passed one of the field's
+            /// bounds. What happens then is up to `overflow`: the count
+            /// either wraps around within the field's bounds or stops at
+            /// the bound it passed.
+            pub fn increment<M, O, U, L>(
+                &mut self,
+                _: F<Width, M, O, U, Register, L>,
+                by: Width,
+                overflow: $crate::Overflow,
+            ) -> bool
+            where
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+                L: ReifyTo<Width>,
+            {
+                let (lower, upper) = (L::reify(), U::reify());
+                let old = self.load();
+                let cur = ((old >> O::reify()) & U::reify()).max(lower);
+                let sum = cur.checked_add(by);
+                let next = match (sum, overflow) {
+                    (Some(n), _) if n >= lower && n <= upper => n,
+                    (Some(n), $crate::Overflow::Saturate) if n < lower => lower,
+                    (_, $crate::Overflow::Saturate) => upper,
+                    (_, $crate::Overflow::Wrap) => {
+                        // The span only fills the whole width when the
+                        // field covers an entire unsigned register, in
+                        // which case wrapping is the width's own.
+                        let span = upper - lower;
+                        if span == Width::MAX {
+                            cur.wrapping_add(by)
+                        } else {
+                            let step = by.rem_euclid(span + 1);
+                            let pos = cur - lower;
+                            if pos > span - step {
+                                lower + pos - (span - step) - 1
+                            } else {
+                                lower + pos + step
+                            }
+                        }
+                    }
+                };
+                let new =
+                    (old & !M::reify() & !Register::W1C_MASK) | ((next << O::reify()) & M::reify());
+                self.store(new);
+                record_modify!(Register::NAME, old, new);
+                sum != Some(next)
+            }
+
+            /// `write_field` sets a single field to the value it holds,
+            /// zeroes and all, leaving the register's other fields as
+            /// they were. It is `modify` restricted to one field, so the
+            /// value is bounded by that field's type alone.
+            pub fn write_field<M, O, U, L>(&mut self, f: F<Width, M, O, U, Register, L>)
+            where
+                U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<Width>,
+                M: Unsigned + ReifyTo<Width>,
+                O: Unsigned + ReifyTo<Width>,
+                L: ReifyTo<Width>,
+            {
+                self.modify(f);
+            }
+
+            /// `apply_config` reprograms every field in the register
+            /// from the given `Config` in a single read-modify-write.
+            /// Bits which no field covers are left as they were.
+            pub fn apply_config(&mut self, cfg: Config) {
+                self.modify(cfg);
+            }
+
+            /// `reconstruct` merges the given `Config`'s fields over the
+            /// register's current value and returns the result, without
+            /// writing it. Bits which no field covers keep whatever they
+            /// hold, unlike in a `Config` written over the whole word.
+            pub fn reconstruct(&self, cfg: Config) -> Width {
+                (self.load() & !cfg.mask()) | cfg.in_position()
+            }
+
+            /// `write` sets the value of the whole register to the
+            /// given `Width` value.
+            ///
+            /// # Safety
+            ///
+            /// Bypasses all assurances by writing a raw value directly to the register.
+            pub unsafe fn write(&mut self, val: Width) {
+                self.store(val);
+            }
+
+            /// `write_ordered` is `write` preceded by a memory fence of
+            /// the given ordering, so that every earlier memory access
+            /// is made before the register is written. It is a fence
+            /// around a plain volatile write, not an atomic access. As
+            /// with `core::sync::atomic::fence`, `Ordering::Relaxed`
+            /// panics.
+            ///
+            /// # Safety
+            ///
+            /// Bypasses all assurances by writing a raw value directly to the register.
+            pub unsafe fn write_ordered(
+                &mut self,
+                val: Width,
+                order: core::sync::atomic::Ordering,
+            ) {
+                core::sync::atomic::fence(order);
+                self.store(val);
+            }
+        }
+
+        impl $crate::Modify for Register {
+            type Width = Width;
+
+            fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
+                Register::modify(self, val);
+            }
+        }
+        impl $crate::Readable for Register {
+            type Width = Width;
+            type Register = Register;
+
+            fn read(&self) -> Width {
+                Register::read(self)
+            }
+        }
+
+        modify_cs!();
+    };
+}
+
+/// `defmt_format` emits a `defmt::Format` implementation for `Register`
+/// when the `defmt` feature is enabled, and nothing otherwise. Like the
+/// generated `Debug`, it shows each field by name, along with the name
+/// of its value for enum-like fields, having read the register once.
+#[cfg(feature = "defmt")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! defmt_format {
+    ($name:ident WO $fields:tt) => {
+        impl $crate::defmt::Format for Register {
+            fn format(&self, f: $crate::defmt::Formatter<'_>) {
+                $crate::defmt::write!(f, "{=str} {{ .. }}", stringify!($name));
+            }
+        }
+    };
+    ($name:ident $mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        impl $crate::defmt::Format for Register {
+            fn format(&self, f: $crate::defmt::Formatter<'_>) {
+                let val = self.load();
+                let mut sep = "";
+                $crate::defmt::write!(f, "{=str} {{ ", stringify!($name));
+                $(
+                    $crate::defmt::write!(f, "{=str}{=str}: ", sep, stringify!($field));
+                    defmt_format!(
+                        @value f,
+                        field_value!(val, $field, $width, $offset),
+                        $field $([$($enums)*])?
+                    );
+                    sep = ", ";
+                )*
+                $crate::defmt::write!(f, " }}");
+            }
+        }
+    };
+    (@value $f:ident, $raw:expr, $field:ident) => {
+        $crate::defmt::write!($f, "{}", $raw);
+    };
+    (@value $f:ident, $raw:expr, $field:ident [
+        $($(#[$outer:meta])* $enum:ident = $val:tt),* $(,)?
+    ]) => {
+        let raw = $raw;
+        $(
+            if raw == $field::$enum.val() {
+                $crate::defmt::write!($f, "{=str}({})", stringify!($enum), raw);
+            } else
+        )* {
+            $crate::defmt::write!($f, "{}", raw);
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! defmt_format {
+    ($($tokens:tt)*) => {};
+}
+
+/// `serialize_fields` emits a `serde::Serialize` implementation for
+/// `Register` when the `serde` feature is enabled, and nothing otherwise.
+/// It reads the register once and serializes a struct with a member per
+/// field, holding the name of its value for enum-like fields which have
+/// one, and the raw value otherwise.
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! serialize_fields {
+    ($name:ident WO $fields:tt) => {};
+    ($name:ident $mode:ident {
+        $($field:ident $width:ident $offset:ident $access:tt $([$($enums:tt)*])?),*
+    }) => {
+        impl $crate::serde::Serialize for Register {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                use $crate::serde::ser::SerializeStruct;
+
+                let val = self.load();
+                let mut state = serializer
+                    .serialize_struct(stringify!($name), [$(stringify!($field)),*].len())?;
+                $(
+                    serialize_fields!(
+                        @field state,
+                        field_value!(val, $field, $width, $offset),
+                        $field $([$($enums)*])?
+                    );
+                )*
+                state.end()
+            }
+        }
+    };
+    (@field $state:ident, $raw:expr, $field:ident) => {
+        $state.serialize_field(stringify!($field), &$raw)?;
+    };
+    (@field $state:ident, $raw:expr, $field:ident [$($enums:tt)*]) => {
+        let raw = $raw;
+        match $field::decode(raw) {
+            Some(named) => $state.serialize_field(stringify!($field), named.name())?,
+            None => $state.serialize_field(stringify!($field), &raw)?,
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! serialize_fields {
+    ($($tokens:tt)*) => {};
+}
+
+/// `bit_band` emits a `bit_band` constructor for single-bit fields when
+/// the `bitband` feature is enabled, and nothing otherwise.
+#[cfg(feature = "bitband")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bit_band {
+    (U1 $offset:ident) => {
+        /// `bit_band` returns the bit-band alias of this field in `reg`,
+        /// which lies in the bit-band region starting at `base` whose
+        /// alias region starts at `alias_base`; see `BitBand`'s
+        /// constants for the usual Cortex-M regions. Bit-band aliases
+        /// count bits in little-endian order from the register's
+        /// address, so the field's bit is found where the register's
+        /// byte order puts it in memory.
+        pub fn bit_band(reg: &Register, base: usize, alias_base: usize) -> $crate::BitBand {
+            let bus = Register::to_bus(1 << <$offset as Unsigned>::USIZE);
+            $crate::BitBand::new(
+                base,
+                alias_base,
+                reg as *const Register as usize,
+                Width::from_le(bus).trailing_zeros() as usize,
+            )
+        }
+    };
+    ($width:ident $offset:ident) => {};
+}
+
+#[cfg(not(feature = "bitband"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bit_band {
+    ($width:ident $offset:ident) => {};
+}
+
+/// `record_modify` pushes a modification onto the crate's history
+/// when the `history` feature is enabled, and does nothing otherwise.
+#[cfg(feature = "history")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! record_modify {
+    ($name:expr, $old:expr, $new:expr) => {
+        // A signed register's value would be sign-extended by the cast,
+        // so only its own bits are kept.
+        let bits = u128::MAX >> (128 - Width::BITS);
+        #[allow(clippy::unnecessary_cast)]
+        $crate::history::record($name, $old as u128 & bits, $new as u128 & bits);
+    };
+}
+
+#[cfg(not(feature = "history"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! record_modify {
+    ($name:expr, $old:expr, $new:expr) => {};
+}
+
+/// `modify_cs` emits the critical-section-aware methods of `RW` registers
+/// when the `critical-section` feature is enabled, and nothing otherwise.
+#[cfg(feature = "critical-section")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! modify_cs {
+    () => {
+        impl Register {
+            /// `modify_cs` is `modify`, performed within the critical
+            /// section `cs` so that an interrupt handler which also
+            /// touches the register can't tear the read-modify-write.
+            pub fn modify_cs<V: Positioned<Width = Width>>(
+                &mut self,
+                _cs: $crate::critical_section::CriticalSection<'_>,
+                val: V,
+            ) {
+                self.modify(val);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "critical-section"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! modify_cs {
+    () => {};
+}
+
+/// `storage` declares and accesses what a register holds its value in:
+/// a `core::cell::UnsafeCell<Width>` when the `cell` feature is enabled,
+/// so that its volatile accesses go through the cell, as Miri would have
+/// them, and a plain `Width` otherwise. Either is laid out as a `Width`.
+#[cfg(feature = "cell")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! storage {
+    (@type) => {
+        core::cell::UnsafeCell<Width>
+    };
+    (@new $val:expr) => {
+        core::cell::UnsafeCell::new($val)
+    };
+    (@read $cell:expr) => {
+        core::ptr::read_volatile($cell.get() as *const Width)
+    };
+    (@write $cell:expr, $val:expr) => {
+        core::ptr::write_volatile($cell.get(), $val)
+    };
+    (@sync) => {
+        // The cell is only ever written through `&mut self`, so sharing
+        // a register is as safe as sharing the `Width` it holds.
+        unsafe impl Sync for Register {}
+    };
+}
+
+#[cfg(not(feature = "cell"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! storage {
+    (@type) => {
+        Width
+    };
+    (@new $val:expr) => {
+        $val
+    };
+    (@read $cell:expr) => {
+        core::ptr::read_volatile(&$cell as *const Width)
+    };
+    (@write $cell:expr, $val:expr) => {
+        core::ptr::write_volatile(&mut $cell as *mut Width, $val)
+    };
+    (@sync) => {};
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Positioned;
+    use typenum::consts::{U0, U1, U16, U2, U32, U4, U5, U8, U9};
+
+    register! {
+        /// The status register
+        #[derive(Debug)]
+        Status,
+        u8,
+        RW,
+        Fields [
+            /// Here I'm just testing that doc comments work.
+            On WIDTH(U1) OFFSET(U0),
+            Dead WIDTH(U1) OFFSET(U1),
+            Color WIDTH(U3) OFFSET(U2) [
+                /// In here too!
+                // Even with a bunch of lines.
+                Red = U1,
+                Blue = U2,
+                Green = U3,
+                Yellow = U4
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_rw_macro() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Field::checked::<U1>());
+        assert_eq!(reg.read(), 2);
+    }
+
+    #[test]
+    fn test_matches_any() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set);
+        assert!(reg.matches_any(Status::On::Set + Status::Dead::Set));
+        reg.modify(Status::Dead::Clear);
+        assert!(!reg.matches_any(Status::On::Set + Status::Dead::Set));
+    }
+
+    #[test]
+    fn test_matches_all() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set + Status::On::Set);
+        assert!(reg.matches_all(Status::On::Set + Status::Dead::Set));
+        reg.modify(Status::Dead::Clear);
+        assert!(!reg.matches_all(Status::On::Set + Status::Dead::Set));
+    }
+
+    #[test]
+    fn test_matches_none() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set + Status::On::Set);
+        assert!(!reg.matches_none(Status::On::Set + Status::Dead::Set));
+        assert!(!reg.extract().matches_none(Status::Dead::Set));
+        reg.modify(Status::Dead::Clear + Status::On::Clear);
+        assert!(reg.matches_none(Status::On::Set + Status::Dead::Set));
+        assert!(reg
+            .extract()
+            .matches_none(Status::On::Set + Status::Dead::Set));
+    }
+
+    #[test]
+    fn test_is_any_field_set() {
+        let mut reg = Status::Register::new(0b1110_0000);
+        assert!(!reg.is_any_field_set());
+        reg.modify(Status::Color::Red);
+        assert!(reg.is_any_field_set());
+    }
+
+    register! {
+        ///  A random number generator
+        #[derive(Debug)]
+        RNG,
+        u8,
+        RO,
+        Fields [
+            /// This field means the RNG is working on generating a
+            /// random number.
+            Working WIDTH(U1) OFFSET(U0),
+            NumWidth WIDTH(U2) OFFSET(U1) [
+                Four = U0,
+                Eight = U1,
+                Sixteen = U2
+            ]
+        ]
+    }
+
+    #[test]
+    fn test_ro_macro() {
+        let reg = RNG::Register::new(4);
+        let width = reg.get_field(RNG::NumWidth::Read).unwrap();
+        assert_eq!(width, RNG::NumWidth::Sixteen);
+    }
+
+    #[test]
+    fn test_field_links() {
+        assert_eq!(
+            field_links!(On, Dead, Color),
+            "\n# Fields\n\n* [`On`](On)\n* [`Dead`](Dead)\n* [`Color`](Color)\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_config() {
+        let mut reg = Status::Register::new(0b1110_0001);
+        reg.apply_config(Status::Config {
+            on: Status::On::Clear,
+            dead: Status::Dead::Set,
+            color: Status::Color::Green,
+        });
+        assert_eq!(reg.read(), 0b1110_1110);
+    }
+
+    #[test]
+    fn test_reconstruct() {
+        let reg = Status::Register::new(0b1010_0001);
+        let val = reg.reconstruct(Status::Config {
+            on: Status::On::Clear,
+            dead: Status::Dead::Set,
+            color: Status::Color::Red,
+        });
+        assert_eq!(val, 0b1010_0110);
+        assert_eq!(reg.read(), 0b1010_0001);
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        use core::convert::TryFrom;
+
+        register! {
+            Wide,
+            u16,
+            RW,
+            Fields [
+                Low WIDTH(U8) OFFSET(U0),
+                High WIDTH(U8) OFFSET(U8)
+            ]
+        }
+
+        let reg = Wide::Register::try_from(&[0x34, 0x12][..]).unwrap();
+        assert_eq!(reg.read(), 0x1234);
+        assert!(Wide::Register::try_from(&[0x34][..]).is_err());
+        assert!(Wide::Register::try_from(&[0x34, 0x12, 0x00][..]).is_err());
+    }
+
+    #[test]
+    fn test_accessors() {
+        register! {
+            Leds,
+            u8,
+            RW,
+            Derive[Accessors],
+            Fields [
+                Power WIDTH(U1) OFFSET(U0),
+                Color WIDTH(U3) OFFSET(U1) [
+                    Red = U1,
+                    Blue = U2
+                ]
+            ]
+        }
+
+        let mut reg = Leds::Register::new(0);
+        assert_eq!(reg.set_color(2), Ok(()));
+        assert_eq!(reg.color(), 2);
+        assert_eq!(reg.set_color(8).map_err(|e| e.upper), Err(7));
+        assert_eq!(reg.color(), 2);
+        assert_eq!(reg.color_value(), Some(Leds::Color::Value::Blue));
+        reg.set_color(5).unwrap();
+        assert_eq!(reg.color_value(), None);
+        reg.set_color_variant(Leds::Color::Value::Blue);
+        reg.set_power(1).unwrap();
+        assert_eq!(reg.power(), 1);
+        assert_eq!(reg.read(), 0b0101);
+    }
+
+    #[test]
+    fn test_dyn_field() {
+        use crate::DynField;
+
+        let mut reg = Status::Register::new(0b0000_0011);
+        assert!(DynField::new(0b0001_1100, 2, 7, 8).is_none());
+        reg.modify(DynField::new(0b0001_1100, 2, 7, 3).unwrap());
+        assert_eq!(reg.read(), 0b0000_1111);
+        assert_eq!(
+            reg.get_field(Status::Color::Read).unwrap(),
+            Status::Color::Green
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "outside of the register's fields")]
+    fn test_modify_undefined_bits() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(crate::DynField::new(0b0110_0000, 5, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut reg = Status::Register::new(0b0000_1001);
+        assert_eq!(
+            reg.replace(Status::Dead::Set + Status::On::Clear),
+            0b0000_1001
+        );
+        assert_eq!(reg.read(), 0b0000_1010);
+        assert_eq!(reg.replace(Status::Color::Yellow), 0b0000_1010);
+        assert_eq!(reg.read(), 0b0001_0010);
+    }
+
+    #[test]
+    fn test_changed_fields() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set + Status::Color::Red);
+        let before = reg.extract();
+        reg.modify(Status::Color::Yellow);
+        let after = reg.extract();
+
+        let mut changed = Status::Register::changed_fields(&before, &after);
+        assert_eq!(changed.next(), Some("Color"));
+        assert_eq!(changed.next(), None);
+    }
+
+    // Every width a register may be built over must support the whole
+    // round trip of modifying, reading, and decoding a field.
+    macro_rules! width_matrix {
+        ($num_type:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_ $num_type _matrix>]() {
+                    register! {
+                        Matrix,
+                        $num_type,
+                        RW,
+                        Fields [
+                            Low WIDTH(U4) OFFSET(U0),
+                            Mode WIDTH(U2) OFFSET(U5) [
+                                Fast = U2
+                            ]
+                        ]
+                    }
+
+                    let mut reg = Matrix::Register::new(0);
+                    reg.modify(Matrix::Low::Field::checked::<U9>() + Matrix::Mode::Fast);
+                    assert_eq!(reg.read(), 0b0100_1001);
+                    assert_eq!(reg.get_field(Matrix::Low::Read).unwrap().val(), 9);
+                    assert_eq!(
+                        Matrix::Mode::try_decode(&reg.get_field(Matrix::Mode::Read).unwrap()),
+                        Some(Matrix::Mode::Value::Fast)
+                    );
+                    reg.modify(Matrix::Low::Clear);
+                    assert_eq!(reg.read(), 0b0100_0000);
+                }
+            }
+        };
+    }
+
+    widths!(width_matrix);
+
+    #[test]
+    fn test_u64_register() {
+        register! {
+            Timer,
+            u64,
+            RW,
+            Fields [
+                Count WIDTH(U32) OFFSET(U0),
+                Reload WIDTH(U16) OFFSET(U32),
+                Mode WIDTH(U2) OFFSET(U62) [
+                    OneShot = U1,
+                    Periodic = U2
+                ]
+            ]
+        }
+
+        let mut reg = Timer::Register::new(0);
+        reg.modify(Timer::Mode::Periodic + Timer::Reload::Field::new(0xabcd).unwrap());
+        assert_eq!(reg.read(), 0x8000_abcd_0000_0000);
+        assert_eq!(reg.get_field(Timer::Reload::Read).unwrap().val(), 0xabcd);
+        assert_eq!(reg.get_field(Timer::Mode::Read).unwrap().val(), 2);
+        reg.modify(Timer::Count::Set);
+        assert_eq!(
+            reg.get_field(Timer::Count::Read).unwrap().val(),
+            0xffff_ffff
+        );
+        assert!(reg.matches_all(Timer::Count::Set + Timer::Mode::Periodic));
+    }
+
+    #[test]
+    fn test_u128_register() {
+        register! {
+            Ctrl,
+            u128,
+            RW,
+            Fields [
+                Low WIDTH(U64) OFFSET(U0),
+                High WIDTH(U64) OFFSET(U64)
+            ]
+        }
+
+        register! {
+            Key,
+            u128,
+            RW,
+            Fields [
+                Whole WIDTH(U128) OFFSET(U0)
+            ]
+        }
+
+        let mut reg = Ctrl::Register::new(0);
+        reg.modify(Ctrl::High::Set + Ctrl::Low::Field::checked::<U5>());
+        assert_eq!(reg.read(), (u128::from(u64::MAX) << 64) | 5);
+        assert_eq!(reg.get_field(Ctrl::Low::Read).unwrap().val(), 5);
+
+        let mut key = Key::Register::new(0);
+        key.modify(Key::Whole::Set);
+        assert_eq!(key.read(), u128::MAX);
+        assert!(key.is_set(Key::Whole::Read));
+    }
+
+    #[test]
+    fn test_reset_value() {
+        register! {
+            Clock,
+            u8,
+            RW,
+            RESET(U9),
+            Fields [
+                Enable WIDTH(U1) OFFSET(U0),
+                Divider WIDTH(U3) OFFSET(U1)
+            ]
+        }
+
+        assert_eq!(Clock::Register::RESET_VALUE, 9);
+        assert_eq!(Clock::Enable::RESET.val(), 1);
+        assert_eq!(Clock::Divider::RESET.val(), 4);
+        let mut reg = Clock::Register::reset();
+        assert!(reg.get_field(Clock::Divider::Read).unwrap() == Clock::Divider::RESET);
+        assert_eq!(reg.read(), 9);
+        reg.modify(Clock::Divider::Set);
+        assert_eq!(reg.read(), 15);
+
+        assert_eq!(Status::Register::RESET_VALUE, 0);
+        assert_eq!(Status::Register::reset().read(), 0);
+    }
+
+    #[test]
+    fn test_wo_apply_config() {
+        register! {
+            Tx,
+            u8,
+            WO,
+            Fields [
+                Data WIDTH(U4) OFFSET(U0),
+                Parity WIDTH(U1) OFFSET(U4)
+            ]
+        }
+
+        let mut reg = Tx::Register::new(0b1110_0000);
+        reg.apply_config(Tx::Config {
+            data: Tx::Data::Field::checked::<U5>(),
+            parity: Tx::Parity::Set,
+        });
+        let raw = unsafe { *(&reg as *const Tx::Register as *const u8) };
+        assert_eq!(raw, 0b0001_0101);
+    }
+
+    #[test]
+    fn test_w1c_field() {
+        register! {
+            Irq,
+            u8,
+            RW,
+            Fields [
+                Pending WIDTH(U1) OFFSET(U0) ACCESS(W1C),
+                Enable WIDTH(U1) OFFSET(U1),
+                Level WIDTH(U2) OFFSET(U2)
+            ]
+        }
+
+        // A latched interrupt must not be cleared by touching its
+        // neighbors.
+        let mut reg = Irq::Register::new(0b0000_0011);
+        reg.modify(Irq::Level::Set);
+        assert_eq!(reg.read(), 0b0000_1110);
+
+        // Setting the flag writes the one which clears it.
+        reg.modify(Irq::Pending::Set);
+        assert_eq!(reg.read(), 0b0000_1111);
+    }
+
+    #[test]
+    fn test_mixed_access() {
+        register! {
+            Uart,
+            u8,
+            RW,
+            Derive[Accessors],
+            Fields [
+                Busy WIDTH(U1) OFFSET(U0) ACCESS(RO),
+                Overrun WIDTH(U1) OFFSET(U1) ACCESS(W1C),
+                Enable WIDTH(U1) OFFSET(U2),
+            ]
+        }
+
+        assert_eq!(Uart::Register::RO_MASK, 0b001);
+        assert_eq!(Uart::Register::W1C_MASK, 0b010);
+
+        // The busy bit is kept as read, and the latched overrun isn't
+        // cleared by enabling the port.
+        let mut reg = Uart::Register::new(0b011);
+        reg.modify(Uart::Enable::Set);
+        assert_eq!(reg.read(), 0b101);
+        assert_eq!(reg.busy(), 1);
+
+        reg.set_overrun(1).unwrap();
+        assert_eq!(reg.read(), 0b111);
+        reg.apply_config(Uart::Config {
+            busy: Uart::Busy::Read,
+            overrun: Uart::Overrun::Clear,
+            enable: Uart::Enable::Set,
+        });
+        assert_eq!(reg.read(), 0b101);
+
+        // Nor by handing the value read straight back.
+        reg.set_overrun(1).unwrap();
+        unsafe { reg.modify_with(|old| old) };
+        assert_eq!(reg.read(), 0b101);
+    }
+
+    #[test]
+    fn test_mixed_access_keeps_read_only() {
+        register! {
+            Uart,
+            u8,
+            RW,
+            Fields [
+                Busy WIDTH(U1) OFFSET(U0) ACCESS(RO),
+                Enable WIDTH(U1) OFFSET(U2),
+            ]
+        }
+
+        let mut reg = Uart::Register::new(0b001);
+        assert_eq!(reg.get_field(Uart::Busy::Read).unwrap().val(), 1);
+
+        // A `DynField` isn't held to the field's owner, but its write
+        // to the busy bit is dropped all the same.
+        reg.modify(crate::DynField::new(0b101, 0, 0b101, 0b100).unwrap());
+        assert_eq!(reg.read(), 0b101);
+
+        unsafe { reg.modify_with(|_| 0) };
+        assert_eq!(reg.read(), 0b001);
+
+        reg.toggle(Uart::Enable::Set);
+        assert_eq!(reg.read(), 0b101);
+
+        reg.overwrite(Uart::Enable::Set);
+        assert_eq!(reg.read(), 0b100);
+    }
+
+    #[cfg(feature = "critical-section")]
+    #[test]
+    fn test_modify_cs() {
+        // Stands in for the token `critical_section::with` would hand
+        // out on the target.
+        let cs = unsafe { critical_section::CriticalSection::new() };
+        let mut reg = Status::Register::new(0);
+        reg.modify_cs(cs, Status::Dead::Set + Status::On::Set);
+        assert_eq!(reg.read(), 3);
+    }
+
+    #[test]
+    fn test_wo_modify_does_not_read() {
+        register! {
+            Cmd,
+            u8,
+            WO,
+            Fields [
+                Opcode WIDTH(U4) OFFSET(U0),
+                Go WIDTH(U1) OFFSET(U4)
+            ]
+        }
+
+        // Reading a write-only register back may return garbage, which
+        // must not leak into what's written.
+        let mut reg = Cmd::Register::new(0xFF);
+        reg.modify(Cmd::Opcode::Field::checked::<U5>());
+        let raw = unsafe { *(&reg as *const Cmd::Register as *const u8) };
+        assert_eq!(raw, 0b0000_0101);
+    }
+
+    #[test]
+    fn test_shadow_register() {
+        use crate::{Readable, ShadowRegister};
+
+        register! {
+            Cmd,
+            u8,
+            WO,
+            Fields [
+                Opcode WIDTH(U4) OFFSET(U0),
+                Go WIDTH(U1) OFFSET(U4)
+            ]
+        }
+
+        let mut reg = Cmd::Register::new(0);
+        let mut shadow = ShadowRegister::new(&mut reg, 0b0000_0011);
+        shadow.modify(Cmd::Go::Set);
+        assert_eq!(shadow.read(), 0b0001_0011);
+        assert_eq!(shadow.get_field(Cmd::Opcode::Read).unwrap().val(), 0b0011);
+        shadow.modify(Cmd::Opcode::Field::checked::<U5>());
+        assert_eq!(shadow.read(), 0b0001_0101);
+        let raw = unsafe { *(&reg as *const Cmd::Register as *const u8) };
+        assert_eq!(raw, 0b0001_0101);
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Color::Blue);
+        reg.toggle(Status::On::Read);
+        assert_eq!(reg.read(), 0b0000_1001);
+        reg.toggle(Status::On::Read);
+        assert_eq!(reg.read(), 0b0000_1000);
+        reg.toggle(Status::Color::Read);
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().val(), 0b101);
+
+        register! {
+            Clock,
+            u8,
+            RW,
+            Fields [
+                Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+                Enable WIDTH(U1) OFFSET(U3),
+            ]
+        }
+
+        // The divisor itself can't be toggled, but its neighbors can,
+        // and leave it in range.
+        let mut reg = Clock::Register::new(7);
+        reg.toggle(Clock::Enable::Read);
+        assert_eq!(reg.read(), 0b1111);
+        assert_eq!(reg.get_field(Clock::Divisor::Read).unwrap().val(), 7);
+    }
+
+    #[test]
+    fn test_zero_and_max() {
+        const ZERO: Status::Color::Field = Status::Color::Field::zero();
+        const MAX: Status::Color::Field = Status::Color::Field::max();
+        assert_eq!(ZERO.val(), 0);
+        assert_eq!(MAX.val(), 0b111);
+        assert_eq!(ZERO, Status::Color::Clear);
+        assert_eq!(MAX, Status::Color::Set);
+    }
+
+    #[test]
+    fn test_sub_field() {
+        register! {
+            Packed,
+            u8,
+            RW,
+            Fields [
+                Nibble WIDTH(U4) OFFSET(U2)
+            ]
+        }
+
+        let mut reg = Packed::Register::new(0);
+        reg.modify(Packed::Nibble::Field::checked::<U9>());
+        assert_eq!(reg.read(), 0b0010_0100);
+
+        let high = Packed::Nibble::sub_field::<U2, U2>();
+        assert_eq!(high.mask(), 0b0011_0000);
+        assert_eq!(reg.get_field(high).unwrap().val(), 0b10);
+        assert_eq!(
+            reg.get_field(Packed::Nibble::sub_field::<U2, U0>())
+                .unwrap()
+                .val(),
+            0b01
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Color::Blue);
+        let color = reg.get_field(Status::Color::Read).unwrap();
+        assert_eq!(color.decode(), Some(Status::Color::Value::Blue));
+        assert_eq!(
+            Status::Color::try_decode(&color),
+            Some(Status::Color::Value::Blue)
+        );
+        assert_eq!(
+            Status::Color::Value::from_field(&Status::Color::Yellow),
+            Some(Status::Color::Value::Yellow)
+        );
+
+        // Encodings without a name are reserved.
+        reg.modify(Status::Color::Set);
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().decode(), None);
+    }
+
+    #[test]
+    fn test_extract_queries() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set + Status::Color::Blue);
+        let copy = reg.extract();
+        reg.modify(Status::Dead::Clear + Status::On::Set);
+
+        assert!(*copy.flag(Status::Dead::Read));
+        assert!(!*copy.flag(Status::On::Read));
+        assert_eq!(copy.field_as::<u32, _, _, _, _, _>(Status::Color::Read), 2);
+        assert!(copy.is_set(Status::Dead::Read));
+        assert!(copy.matches_all(Status::Dead::Set + Status::Color::Blue));
+        assert!(copy.matches_none(Status::On::Set));
+    }
+
+    #[test]
+    fn test_extract_single_read() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::On::Set + Status::Dead::Set);
+
+        // Each check on the copy sees the same value, however the
+        // register changes in between.
+        let copy = reg.extract();
+        assert!(copy.is_set(Status::On::Read));
+        reg.modify(Status::On::Clear);
+        assert!(!reg.is_set(Status::On::Read));
+        assert!(copy.matches_any(Status::On::Set));
+        reg.modify(Status::Dead::Clear);
+        assert!(!reg.matches_all(Status::On::Set + Status::Dead::Set));
+        assert!(copy.matches_all(Status::On::Set + Status::Dead::Set));
+        assert_eq!(copy.read(), 0b11);
+    }
+
+    #[test]
+    fn test_modify_with() {
+        let mut reg = Status::Register::new(0b0000_0010);
+        let on = Status::On::Set.mask();
+        let dead = Status::Dead::Set.mask();
+
+        // Set `On` only if `Dead` is set.
+        unsafe { reg.modify_with(|old| if old & dead != 0 { old | on } else { old }) };
+        assert_eq!(reg.read(), 0b0000_0011);
+
+        let mut reg = Status::Register::new(0);
+        unsafe { reg.modify_with(|old| if old & dead != 0 { old | on } else { old }) };
+        assert_eq!(reg.read(), 0);
+    }
+
+    #[test]
+    fn test_from_percent() {
+        register! {
+            Pwm,
+            u16,
+            RW,
+            Fields [
+                Duty WIDTH(U8) OFFSET(U0),
+                Prescale WIDTH(U3) OFFSET(U8)
+            ]
+        }
+
+        assert_eq!(Pwm::Duty::Field::from_percent(50).unwrap().val(), 127);
+        assert_eq!(Pwm::Duty::Field::from_percent(0).unwrap().val(), 0);
+        assert_eq!(Pwm::Duty::Field::from_percent(100).unwrap().val(), 255);
+        assert!(Pwm::Duty::Field::from_percent(101).is_none());
+        assert_eq!(Pwm::Prescale::Field::from_percent(50).unwrap().val(), 3);
+    }
+
+    #[cfg(feature = "bitband")]
+    #[test]
+    fn test_bit_band() {
+        use crate::BitBand;
+
+        let band = BitBand::new(
+            BitBand::PERIPHERAL_BASE,
+            BitBand::PERIPHERAL_ALIAS,
+            0x4001_0c0c,
+            5,
+        );
+        assert_eq!(band.alias(), 0x4221_8194);
+
+        let reg = Status::Register::new(0);
+        let addr = &reg as *const Status::Register as usize;
+        let band = Status::Dead::bit_band(&reg, addr, 0x1000);
+        assert_eq!(band.alias(), 0x1000 + 4);
+
+        register! {
+            Swapped,
+            u16,
+            RW,
+            ENDIAN(BE),
+            Fields [
+                Low WIDTH(U1) OFFSET(U1),
+                High WIDTH(U1) OFFSET(U9),
+            ]
+        }
+
+        // Stored big-endian, the low byte comes second in memory.
+        let reg = Swapped::Register::new(0);
+        let addr = &reg as *const Swapped::Register as usize;
+        let band = Swapped::Low::bit_band(&reg, addr, 0x1000);
+        assert_eq!(band.alias(), 0x1000 + 9 * 4);
+        let band = Swapped::High::bit_band(&reg, addr, 0x1000);
+        assert_eq!(band.alias(), 0x1000 + 4);
+    }
+
+    #[test]
+    fn test_const_decode() {
+        use Status::Color::Value;
+
+        const TABLE: [Option<Value>; 8] = {
+            let mut table = [None; 8];
+            let mut i = 0;
+            while i < table.len() {
+                table[i] = Status::Color::decode(i as u8);
+                i += 1;
+            }
+            table
+        };
+
+        assert_eq!(
+            TABLE,
+            [
+                None,
+                Some(Value::Red),
+                Some(Value::Blue),
+                Some(Value::Green),
+                Some(Value::Yellow),
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_name_of() {
+        const BLUE: Option<&str> = Status::Color::name_of(2);
+        assert_eq!(BLUE, Some("Blue"));
+        assert_eq!(Status::Color::name_of(4), Some("Yellow"));
+        assert_eq!(Status::Color::name_of(0), None);
+        assert_eq!(Status::Color::name_of(7), None);
+    }
+
+    #[test]
+    fn test_packed() {
+        const EXPECTED: u8 = Status::Dead::Set.packed() | Status::Color::Blue.packed();
+        assert_eq!(EXPECTED, 0b0000_1010);
+
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set + Status::Color::Blue);
+        assert_eq!(reg.read(), EXPECTED);
+    }
+
+    #[test]
+    fn test_value_conversions() {
+        use core::convert::TryFrom;
+        use Status::Color::Value;
+
+        assert_eq!(Value::try_from(3), Ok(Value::Green));
+        assert_eq!(Value::try_from(6), Err(6));
+        assert_eq!(u8::from(Value::Yellow), 4);
+        for val in 0..8 {
+            if let Ok(value) = Value::try_from(val) {
+                assert_eq!(u8::from(value), val);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_descriptors() {
+        let fields = Status::Register::field_descriptors();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(
+            fields[2],
+            crate::FieldDescriptor {
+                name: "Color",
+                offset: 2,
+                width: 3,
+                mask: 0b0001_1100,
+            }
+        );
+        let names = fields.iter().map(|f| f.name);
+        assert!(names.eq(Status::Register::ALL_FIELDS.iter().map(|(n, _)| *n)));
+    }
+
+    #[test]
+    fn test_reserved_field() {
+        register! {
+            Control,
+            u8,
+            RW,
+            Derive[Accessors],
+            Fields [
+                Enable WIDTH(U1) OFFSET(U0),
+                Reserved WIDTH(U3) OFFSET(U5) ACCESS(RESERVED)
+            ]
+        }
+
+        let mut reg = Control::Register::new(0b1010_0000);
+        reg.modify(Control::Enable::Set);
+        reg.apply_config(Control::Config {
+            enable: Control::Enable::Set,
+            reserved: Control::Reserved::Read,
+        });
+        assert_eq!(reg.read(), 0b1010_0001);
+        assert_eq!(reg.reserved(), 0b101);
+        assert_eq!(Control::Register::field_at(6), Some("Reserved"));
+        assert_eq!(Control::Register::DEFINED_MASK, 0b1110_0001);
+    }
+
+    #[test]
+    fn test_read_fields() {
+        let reg = Status::Register::new(0b0000_1010);
+        let values = reg.read_fields();
+        assert_eq!(
+            values,
+            Status::Values {
+                on: 0,
+                dead: 1,
+                color: 2,
             }
+        );
+        assert_eq!(
+            Status::Color::decode(values.color),
+            Some(Status::Color::Value::Blue)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate std;
+        use std::format;
+
+        register! {
+            Light,
+            u8,
+            RW,
+            Derive[Display],
+            Fields [
+                On WIDTH(U1) OFFSET(U0),
+                Color WIDTH(U3) OFFSET(U2) [
+                    Red = U1,
+                    Blue = U2
+                ],
+            ]
+        }
+
+        let reg = Light::Register::new(0b0000_1001);
+        assert_eq!(format!("{}", reg), "0b000_010_0_1 (On=1 Color=Blue(2))");
+    }
+
+    #[test]
+    fn test_field_values() {
+        assert!(Status::Color::values().map(|f| f.val()).eq(0..=7));
+        assert!(Status::Color::variants().map(|f| f.val()).eq([1, 2, 3, 4]));
+        let names = Status::Color::variants().filter_map(|f| Status::Color::try_decode(&f));
+        assert!(names.eq([
+            Status::Color::Value::Red,
+            Status::Color::Value::Blue,
+            Status::Color::Value::Green,
+            Status::Color::Value::Yellow,
+        ]));
+    }
+
+    #[test]
+    fn test_endian() {
+        register! {
+            Remote,
+            u16,
+            RW,
+            ENDIAN(BE),
+            Fields [
+                Low WIDTH(U8) OFFSET(U0),
+                High WIDTH(U8) OFFSET(U8)
+            ]
+        }
+
+        let mut reg = Remote::Register::new(0x1234);
+        let raw = unsafe { *(&reg as *const Remote::Register as *const u16) };
+        assert_eq!(raw.to_ne_bytes(), [0x12, 0x34]);
+        assert_eq!(reg.read(), 0x1234);
+
+        reg.modify(Remote::High::Field::new(0xab).unwrap());
+        assert_eq!(reg.read(), 0xab34);
+        assert_eq!(reg.get_field(Remote::Low::Read).unwrap().val(), 0x34);
+        let raw = unsafe { *(&reg as *const Remote::Register as *const u16) };
+        assert_eq!(raw.to_ne_bytes(), [0xab, 0x34]);
+
+        // Bytes taken off the bus are in the register's order too.
+        let reg =
+            <Remote::Register as core::convert::TryFrom<_>>::try_from(&[0x12, 0x34][..]).unwrap();
+        assert_eq!(reg.read(), 0x1234);
+        let raw = unsafe { *(&reg as *const Remote::Register as *const u16) };
+        assert_eq!(raw.to_ne_bytes(), [0x12, 0x34]);
+    }
+
+    #[test]
+    fn test_slice() {
+        register! {
+            Split,
+            u32,
+            RO,
+            Fields [
+                Whole WIDTH(U32) OFFSET(U0)
+            ]
+        }
+
+        let reg = Split::Register::new(0xdead_beef);
+        assert_eq!(reg.slice::<U4, U8>().val, 0xe);
+        assert_eq!(reg.slice::<U16, U0>().val, 0xbeef);
+        assert_eq!(reg.slice::<U16, U16>().val, 0xdead);
+        assert_eq!(reg.slice::<U32, U0>().val, 0xdead_beef);
+    }
+
+    #[test]
+    fn test_field_disj_fold() {
+        use crate::{DynField, FieldDisj};
+
+        let fields = [
+            DynField::new(0b0000_0001, 0, 1, 1).unwrap(),
+            DynField::new(0b0001_1100, 2, 7, 3).unwrap(),
+        ];
+        let disj = fields.iter().fold(FieldDisj::empty(), |acc, f| acc + *f);
+        let mut reg = Status::Register::new(0b1110_0010);
+        reg.modify(disj + Status::Dead::Clear);
+        assert_eq!(reg.read(), 0b1110_1101);
+
+        let none = FieldDisj::<u8>::empty();
+        assert_eq!((none.mask(), none.in_position()), (0, 0));
+        reg.modify(none);
+        assert_eq!(reg.read(), 0b1110_1101);
+    }
+
+    #[test]
+    fn test_raw_eq() {
+        register! {
+            Shadow,
+            u8,
+            RW,
+            Fields [
+                Color WIDTH(U3) OFFSET(U2)
+            ]
+        }
+
+        let live = Status::Register::new(0b0000_1000);
+        let mut shadow = Shadow::Register::new(0);
+        shadow.modify(Shadow::Color::Field::checked::<U2>());
+        let live_color = live.get_field(Status::Color::Read).unwrap();
+        let shadow_color = shadow.get_field(Shadow::Color::Read).unwrap();
+        assert!(live_color.raw_eq(&shadow_color));
+        assert!(!live_color.raw_eq(&Shadow::Color::Read));
+    }
+
+    #[test]
+    fn test_field_at() {
+        assert_eq!(Status::Register::field_at(0), Some("On"));
+        assert_eq!(Status::Register::field_at(3), Some("Color"));
+        assert_eq!(Status::Register::field_at(5), None);
+        assert_eq!(Status::Register::field_at(64), None);
+    }
+
+    #[test]
+    fn test_field_layout_consts() {
+        assert_eq!(Status::Color::OFFSET, 2);
+        assert_eq!(Status::Color::MASK, 0b0001_1100);
+        assert_eq!(Status::Color::WIDTH, 3);
+        assert_eq!(Status::On::MASK | Status::Dead::MASK, 0b0000_0011);
+    }
+
+    #[test]
+    fn test_extract_const() {
+        const COLOR: u8 = Status::Color::extract_const(0b0000_1010);
+        const _: () = assert!(Status::Dead::extract_const(0b0000_1010) == 1);
+        assert_eq!(COLOR, 2);
+        assert_eq!(Status::On::extract_const(0b0000_1010), 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut reg = Status::Register::new(0b0001_1111);
+        reg.clear();
+        assert_eq!(reg.read(), 0);
+    }
+
+    #[test]
+    fn test_bit_ranges() {
+        register! {
+            Ranged,
+            u16,
+            RW,
+            Derive[Accessors],
+            Fields [
+                /// Bits may be given as a range.
+                Low BITS(U0..U3),
+                Mid WIDTH(U4) OFFSET(U4),
+                Ready BITS(U8..U8) ACCESS(RO),
+                High BITS(U12..U15) [
+                    Slow = U1,
+                    Fast = 0xF
+                ],
+            ]
+        }
+
+        assert_eq!(Ranged::Low::MASK, 0x000f);
+        assert_eq!(Ranged::Ready::MASK, 0x0100);
+        assert_eq!(Ranged::High::OFFSET, 12);
+        assert_eq!(Ranged::High::WIDTH, 4);
+
+        let mut reg = Ranged::Register::new(0x0100);
+        reg.modify(Ranged::High::Fast + Ranged::Low::Field::new(9).unwrap());
+        assert_eq!(reg.read(), 0xf109);
+        assert_eq!(reg.ready(), 1);
+    }
+
+    #[test]
+    fn test_cfg_fields() {
+        // The two layouts overlap, so they'd be rejected were both of
+        // them compiled in. `any()` is always false and `all()` always
+        // true, so each revision takes one fork of the same fields.
+        register! {
+            First,
+            u8,
+            RW,
+            STRICT,
+            Derive[Debug],
+            Fields [
+                Enable WIDTH(U1) OFFSET(U0),
+                /// Present on every revision but the first.
+                #[cfg(any())]
+                Speed WIDTH(U7) OFFSET(U1),
+                #[cfg(all())]
+                /// Only the first revision has a mode.
+                Mode BITS(U1..U7),
+            ]
+        }
+
+        register! {
+            Second,
+            u8,
+            RW,
+            STRICT,
+            Derive[Debug],
+            Fields [
+                Enable WIDTH(U1) OFFSET(U0),
+                #[cfg(all())]
+                Speed WIDTH(U7) OFFSET(U1),
+                #[cfg(any())]
+                Mode BITS(U1..U7),
+            ]
+        }
+
+        let fields = First::Register::ALL_FIELDS;
+        assert_eq!(fields.len(), 2);
+        assert_eq!([fields[0].0, fields[1].0], ["Enable", "Mode"]);
+        assert_eq!(First::Register::field_descriptors().len(), 2);
+        assert_eq!(First::Register::DEFINED_MASK, 0xff);
+
+        let fields = Second::Register::ALL_FIELDS;
+        assert_eq!(fields.len(), 2);
+        assert_eq!([fields[0].0, fields[1].0], ["Enable", "Speed"]);
+        assert_eq!(Second::Register::field_descriptors().len(), 2);
+        assert_eq!(Second::Register::DEFINED_MASK, 0xff);
+    }
+
+    #[test]
+    fn test_register_bits() {
+        register! {
+            Packed,
+            u32,
+            RW,
+            BITS(U24),
+            STRICT,
+            Fields [
+                Low WIDTH(U12) OFFSET(U0),
+                High BITS(U12..U23),
+            ]
+        }
+
+        assert_eq!(Packed::Register::BITS, 24);
+        assert_eq!(Packed::Register::BITS_MASK, 0x00ff_ffff);
+        assert_eq!(Status::Register::BITS, 8);
+        assert_eq!(Status::Register::BITS_MASK, 0xff);
+
+        let mut reg = Packed::Register::new(0xff00_0000);
+        reg.modify(Packed::High::Set);
+        assert_eq!(reg.read(), 0xffff_f000);
+    }
+
+    #[cfg(not(feature = "cell"))]
+    #[test]
+    fn test_derive_clone_copy() {
+        register! {
+            Captured,
+            u16,
+            RW,
+            ENDIAN(BE),
+            Derive[Clone, Copy],
+            Fields [
+                Low WIDTH(U8) OFFSET(U0),
+                High WIDTH(U8) OFFSET(U8)
+            ]
+        }
+
+        let mut reg = Captured::Register::new(0x1234);
+        let copy = reg;
+        reg.modify(Captured::High::Clear);
+        assert_eq!(copy.read(), 0x1234);
+        assert_eq!(reg.clone().read(), 0x0034);
+    }
+
+    #[test]
+    fn test_is_set_proof() {
+        use crate::IsSet;
+
+        fn revive(_: IsSet<'_, Status::Register, Status::Dead::Field>) -> bool {
+            true
+        }
+
+        let mut reg = Status::Register::new(0);
+        assert!(IsSet::check(&reg, Status::Dead::Read).is_none());
+        reg.modify(Status::Dead::Set);
+        let proof = IsSet::check(&reg, Status::Dead::Read).unwrap();
+        // The proof is `Copy`, though `Status::Dead::Field` isn't.
+        let copy = proof;
+        assert!(revive(proof));
+        assert!(revive(copy));
+        assert!(IsSet::check(&reg, Status::Color::Read).is_none());
+        reg.modify(Status::Color::Set);
+        assert!(IsSet::check(&reg, Status::Color::Read).is_some());
+    }
+
+    #[test]
+    fn test_reg_block_transaction() {
+        use core::sync::atomic::Ordering;
+
+        #[repr(C)]
+        struct Block {
+            status: Status::Register,
+            data: Status::Register,
+        }
+
+        let mut mem = [0_u8; 2];
+        let mut block: crate::RegBlock<Block> =
+            unsafe { crate::RegBlock::new(&mut mem as *mut [u8; 2] as usize) };
+
+        let seen = block.transaction(Ordering::SeqCst, |b| {
+            b.status.modify(Status::On::Set);
+            b.data.modify(Status::Color::Blue);
+            b.status.read()
+        });
+        assert_eq!(seen, 0b0000_0001);
+        assert_eq!(block.data.read(), 0b0000_1000);
+        assert_eq!(mem, [0b0000_0001, 0b0000_1000]);
+    }
+
+    #[test]
+    fn test_register_layout() {
+        use core::mem::{align_of, size_of};
+
+        // Whatever the register holds its value in, it must overlay the
+        // hardware's word exactly.
+        register! {
+            Word,
+            u32,
+            RW,
+            Fields [
+                Low WIDTH(U16) OFFSET(U0)
+            ]
+        }
+
+        assert_eq!(size_of::<Status::Register>(), size_of::<u8>());
+        assert_eq!(size_of::<Word::Register>(), size_of::<u32>());
+        assert_eq!(align_of::<Word::Register>(), align_of::<u32>());
+    }
+
+    #[test]
+    fn test_register_try_new() {
+        use crate::{FieldError, InitError};
+
+        register! {
+            Clock,
+            u8,
+            RW,
+            Fields [
+                Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+                Reserved WIDTH(U2) OFFSET(U3) ACCESS(RESERVED),
+                Enable WIDTH(U1) OFFSET(U6),
+            ]
+        }
+
+        assert_eq!(
+            Clock::Register::try_new(0b0100_0011).unwrap().read(),
+            0b0100_0011
+        );
+        assert_eq!(
+            Clock::Register::try_new(0b0100_0000).err(),
+            Some(InitError::OutOfBounds(
+                "Divisor",
+                FieldError {
+                    val: 0,
+                    lower: 1,
+                    upper: 7
+                }
+            ))
+        );
+        assert_eq!(
+            Clock::Register::try_new(0b0000_1001).err(),
+            Some(InitError::Reserved("Reserved"))
+        );
+        assert_eq!(
+            Clock::Register::try_new(0b1000_0001).err(),
+            Some(InitError::Undefined(0b1000_0000))
+        );
+    }
+
+    #[test]
+    fn test_from_fields() {
+        let values = Status::Values {
+            on: 1,
+            dead: 0,
+            color: 2,
+        };
+        let reg = Status::Register::from_fields(values).unwrap();
+        assert_eq!(reg.read(), 0b0000_1001);
+        assert_eq!(reg.read_fields(), values);
+
+        let err = Status::Register::from_fields(Status::Values { color: 8, ..values });
+        assert_eq!(
+            err.err(),
+            Some(crate::InitError::OutOfBounds(
+                "Color",
+                crate::FieldError {
+                    val: 8,
+                    lower: 0,
+                    upper: 7
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_register_meta() {
+        use crate::RegisterMeta;
+
+        fn describe<R: RegisterMeta>() -> (&'static str, u32, u32, R::Width) {
+            (R::NAME, R::BIT_WIDTH, R::BITS, R::DEFINED_MASK)
+        }
+
+        register! {
+            Wide,
+            u64,
+            WO,
+            BITS(U48),
+            Fields [
+                Addr WIDTH(U40) OFFSET(U0)
+            ]
+        }
+
+        assert_eq!(describe::<Status::Register>(), ("Status", 8, 8, 0b1_1111));
+        assert_eq!(
+            describe::<Wide::Register>(),
+            ("Wide", 64, 48, 0xff_ffff_ffff)
+        );
+    }
+
+    #[test]
+    fn test_register_array_read_into() {
+        use crate::RegisterArray;
+
+        let mut fifo = [3_u8, 1, 4, 1];
+        let window =
+            unsafe { &*(&mut fifo as *mut [u8; 4] as *const RegisterArray<Status::Register, 4>) };
 
-            /// `get_field` takes a field and sets the value of that
-            /// field to its value in the register.
-            pub fn get_field<M: Unsigned, O: Unsigned, U: Unsigned>(
-                &self,
-                f: F<Width, M, O, U, Register>,
-            ) -> Option<F<Width, M, O, U, Register>>
-            where
-                U: IsGreater<U0, Output = True> + ReifyTo<Width>,
-                M: ReifyTo<Width>,
-                O: ReifyTo<Width>,
-                U0: ReifyTo<Width>,
-            {
-                f.set(
-                    (unsafe { ptr::read_volatile(&self.0 as *const Width) } & M::reify())
-                        >> O::reify(),
-                )
-            }
+        let mut short = [0_u8; 3];
+        assert_eq!(window.read_into(&mut short), 3);
+        assert_eq!(short, [3, 1, 4]);
 
-            /// `read` returns the current state of the register as a `Width`.
-            pub fn read(&self) -> Width {
-                unsafe { ptr::read_volatile(&self.0 as *const Width) }
-            }
+        let mut long = [0_u8; 6];
+        assert_eq!(window.read_into(&mut long), 4);
+        assert_eq!(long, [3, 1, 4, 1, 0, 0]);
 
-            /// `extract` pulls the state of a register out into a wrapped
-            /// read-only register.
-            pub fn extract(&self) -> $crate::ReadOnlyCopy<Width, Register> {
-                $crate::ReadOnlyCopy(
-                    unsafe { ptr::read_volatile(&self.0 as *const Width) },
-                    PhantomData,
-                )
-            }
+        assert_eq!(window.read_into(&mut []), 0);
+    }
 
-            /// `is_set` takes a field and returns true if that field's value
-            /// is equal to its upper bound or not. This is of particular use
-            /// in single-bit fields.
-            pub fn is_set<M: Unsigned, O: Unsigned, U: Unsigned>(
-                &self,
-                f: F<Width, M, O, U, Register>,
-            ) -> bool
-            where
-                U: IsGreater<U0, Output = True>,
-                U: ReifyTo<Width>,
-                M: ReifyTo<Width>,
-                O: ReifyTo<Width>,
-            {
-                ((unsafe { ptr::read_volatile(&self.0 as *const Width) } & M::reify())
-                    >> O::reify())
-                    == U::reify()
-            }
+    #[test]
+    fn test_reg_block_at_offset() {
+        let mut mem = [0b0000_0001_u8, 0, 0b0000_1000];
+        // The block is anchored in the middle of its registers.
+        let mut block: crate::RegBlock<Status::Register> =
+            unsafe { crate::RegBlock::new(mem.as_mut_ptr().wrapping_add(1) as usize) };
 
-            /// `matches_any` returns whether or not any of the given fields
-            /// match those fields values inside the register.
-            pub fn matches_any<V: Positioned<Width = Width>>(&self, val: V) -> bool {
-                (val.in_position() & unsafe { ptr::read_volatile(&self.0 as *const Width) }) != 0
-            }
+        let before: &Status::Register = unsafe { block.at_offset(-1) };
+        assert!(before.is_set(Status::On::Read));
+        let after: &mut Status::Register = unsafe { block.at_offset_mut(1) };
+        after.modify(Status::Dead::Set);
+        block.modify(Status::Color::Red);
+        assert_eq!(mem, [0b0000_0001, 0b0000_0100, 0b0000_1010]);
+    }
 
-            /// `matches_all` returns whether or not all of the given fields
-            /// match those fields values inside the register.
-            pub fn matches_all<V: Positioned<Width = Width>>(&self, val: V) -> bool {
-                (val.in_position() & unsafe { ptr::read_volatile(&self.0 as *const Width) })
-                    == val.in_position()
-            }
-        }
-    };
-    (WO) => {
-        impl Register {
-            /// `new` constructs a write-only register around the
-            /// given pointer.
-            pub fn new(init: Width) -> Self {
-                Register(init)
-            }
+    #[test]
+    fn test_get_field_by() {
+        let copy = Status::Register::new(0b0000_1010).extract();
+        let color = copy.get_field_by::<Status::Color::Field>().unwrap();
+        assert_eq!(color, Status::Color::Blue);
+        assert_eq!(copy.get_field_by::<Status::Dead::Field>().unwrap().val(), 1);
+    }
 
-            /// `modify` takes one or more fields, joined by `+`, and
-            /// sets those fields in the register, leaving the others
-            /// as they were.
-            pub fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
-                unsafe {
-                    ptr::write_volatile(
-                        &mut self.0 as *mut Width,
-                        (ptr::read_volatile(&self.0 as *const Width) & !val.mask())
-                            | val.in_position(),
-                    );
-                };
-            }
+    #[test]
+    fn test_builder() {
+        let mut reg = Status::Register::new(0b0000_0001);
+        reg.modify(
+            Status::build()
+                .dead(Status::Dead::Set)
+                .color(Status::Color::Blue),
+        );
+        assert_eq!(reg.read(), 0b0000_1011);
 
-            /// `write` sets the value of the whole register to the
-            /// given `Width` value.
-            ///
-            /// # Safety
-            ///
-            /// Bypasses all assurances by writing a raw value directly to the register.
-            pub unsafe fn write(&mut self, val: Width) {
-                ptr::write_volatile(&mut self.0 as *mut Width, val);
-            }
+        let on = false;
+        let mut builder = Status::build().color(Status::Color::Yellow);
+        if on {
+            builder = builder.on(Status::On::Set);
         }
-    };
-    (RW) => {
-        impl Register {
-            /// `new` constructs a read-write register around the
-            /// given pointer.
-            pub fn new(init: Width) -> Self {
-                Register(init)
-            }
+        assert_eq!(builder.pack(), 0b0001_0000);
+        reg.modify(builder);
+        assert_eq!(reg.read(), 0b0001_0011);
+    }
 
-            /// `get_field` takes a field and sets the value of that
-            /// field to its value in the register.
-            pub fn get_field<M: Unsigned, O: Unsigned, U: Unsigned>(
-                &self,
-                f: F<Width, M, O, U, Register>,
-            ) -> Option<F<Width, M, O, U, Register>>
-            where
-                U: IsGreater<U0, Output = True> + ReifyTo<Width>,
-                M: ReifyTo<Width>,
-                O: ReifyTo<Width>,
-                U0: ReifyTo<Width>,
-            {
-                f.set(
-                    (unsafe { ptr::read_volatile(&self.0 as *const Width) } & M::reify())
-                        >> O::reify(),
-                )
-            }
+    #[test]
+    fn test_strict_register() {
+        register! {
+            /// Every bit is accounted for.
+            Covered,
+            u8,
+            RW,
+            RESET(U4),
+            ENDIAN(LE),
+            STRICT,
+            Derive[Debug],
+            Fields [
+                Enable WIDTH(U1) OFFSET(U0),
+                Reserved WIDTH(U1) OFFSET(U1) ACCESS(RESERVED),
+                Mode WIDTH(U2) OFFSET(U2),
+                Prescale WIDTH(U4) OFFSET(U4)
+            ]
+        }
 
-            /// `read` returns the current state of the register as a `Width`.
-            pub fn read(&self) -> Width {
-                unsafe { ptr::read_volatile(&self.0 as *const Width) }
-            }
+        let mut reg = Covered::Register::reset();
+        reg.modify(Covered::Enable::Set);
+        assert_eq!(reg.read(), 0b0000_0101);
+        assert_eq!(Covered::Register::DEFINED_MASK, 0xff);
+    }
 
-            /// `extract` pulls the state of a register out into a wrapped
-            /// read-only register.
-            pub fn extract(&self) -> $crate::ReadOnlyCopy<Width, Register> {
-                $crate::ReadOnlyCopy(
-                    unsafe { ptr::read_volatile(&self.0 as *const Width) },
-                    PhantomData,
-                )
+    #[test]
+    fn test_bank() {
+        // The bank finds its registers through its parent module, so
+        // they can't be declared in a function's body.
+        mod paged {
+            register! {
+                Select,
+                u16,
+                RW,
+                Fields [
+                    Page WIDTH(U2) OFFSET(U4)
+                ]
             }
 
-            /// `is_set` takes a field and returns true if that field's value
-            /// is equal to its upper bound or not. This is of particular use
-            /// in single-bit fields.
-            pub fn is_set<M: Unsigned, O: Unsigned, U: Unsigned>(
-                &self,
-                f: F<Width, M, O, U, Register>,
-            ) -> bool
-            where
-                U: IsGreater<U0, Output = True>,
-                U: ReifyTo<Width>,
-                M: ReifyTo<Width>,
-                O: ReifyTo<Width>,
-            {
-                ((unsafe { ptr::read_volatile(&self.0 as *const Width) } & M::reify())
-                    >> O::reify())
-                    == U::reify()
+            register! {
+                Low,
+                u16,
+                RW,
+                Fields [
+                    Val WIDTH(U16) OFFSET(U0)
+                ]
             }
 
-            /// `matches_any` returns whether or not any of the given fields
-            /// match those fields values inside the register.
-            pub fn matches_any<V: Positioned<Width = Width>>(&self, val: V) -> bool {
-                (val.in_position() & unsafe { ptr::read_volatile(&self.0 as *const Width) }) != 0
+            register! {
+                High,
+                u16,
+                RO,
+                Fields [
+                    Val WIDTH(U16) OFFSET(U0)
+                ]
             }
 
-            /// `matches_all` returns whether or not all of the given fields
-            /// match those fields values inside the register.
-            pub fn matches_all<V: Positioned<Width = Width>>(&self, val: V) -> bool {
-                (val.in_position() & unsafe { ptr::read_volatile(&self.0 as *const Width) })
-                    == val.in_position()
+            bank! {
+                Paged,
+                u16,
+                SELECT(Select::Page),
+                Banks [
+                    First = 0b01 => Low,
+                    Second = U2 => High,
+                ]
             }
+        }
+        use paged::*;
 
-            /// `modify` takes one or more fields, joined by `+`, and
-            /// sets those fields in the register, leaving the others
-            /// as they were.
-            pub fn modify<V: Positioned<Width = Width>>(&mut self, val: V) {
-                unsafe {
-                    ptr::write_volatile(
-                        &mut self.0 as *mut Width,
-                        (ptr::read_volatile(&self.0 as *const Width) & !val.mask())
-                            | val.in_position(),
-                    );
-                };
-            }
+        let mut select = Select::Register::new(0xff0f);
+        let mut bank = Paged::Bank::new(0);
+        assert!(bank.first(&mut select).is_none());
+        assert!(bank.second(&mut select).is_none());
 
-            /// `write` sets the value of the whole register to the
-            /// given `Width` value.
-            ///
-            /// # Safety
-            ///
-            /// Bypasses all assurances by writing a raw value directly to the register.
-            pub unsafe fn write(&mut self, val: Width) {
-                ptr::write_volatile(&mut self.0 as *mut Width, val);
-            }
+        bank.select_first(&mut select)
+            .modify(Low::Val::Field::new(0x1234).unwrap());
+        assert_eq!(select.read(), 0xff1f);
+        assert_eq!(bank.first(&mut select).unwrap().read(), 0x1234);
+        assert!(bank.second(&mut select).is_none());
+
+        assert_eq!(bank.select_second(&mut select).read(), 0x1234);
+        assert_eq!(select.read(), 0xff2f);
+    }
+
+    #[test]
+    fn test_signed_register() {
+        register! {
+            Trim,
+            i16,
+            RW,
+            Fields [
+                Offset WIDTH(U12) OFFSET(U0),
+                Gain WIDTH(U4) OFFSET(U12)
+            ]
         }
-    };
-}
 
-#[cfg(test)]
-mod test {
-    use typenum::consts::U1;
+        let mut reg = Trim::Register::new(0);
+        reg.modify(Trim::Offset::Field::new(0xffe).unwrap() + Trim::Gain::Field::checked::<U9>());
+        assert_eq!(reg.read(), -0x6002);
+        assert_eq!(reg.get_field(Trim::Offset::Read).unwrap().val(), 0xffe);
+        assert_eq!(reg.get_field(Trim::Gain::Read).unwrap().val(), 9);
+        assert_eq!(reg.get_signed(Trim::Offset::Read), -2);
+        assert_eq!(reg.get_signed(Trim::Gain::Read), -7);
+        assert_eq!(Trim::Gain::extract_const(-0x6002), 9);
 
-    register! {
-        /// The status register
-        #[derive(Debug)]
-        Status,
-        u8,
-        RW,
-        Fields [
-            /// Here I'm just testing that doc comments work.
-            On WIDTH(U1) OFFSET(U0),
-            Dead WIDTH(U1) OFFSET(U1),
-            Color WIDTH(U3) OFFSET(U2) [
-                /// In here too!
-                // Even with a bunch of lines.
-                Red = U1,
-                Blue = U2,
-                Green = U3,
-                Yellow = U4
-            ],
-        ]
+        reg.modify(Trim::Offset::Field::new(0x7ff).unwrap() + Trim::Gain::Field::checked::<U5>());
+        assert_eq!(reg.get_signed(Trim::Offset::Read), 0x7ff);
+        assert_eq!(reg.get_signed(Trim::Gain::Read), 5);
+        let copy = reg.extract();
+        assert_eq!(copy.get_field(Trim::Gain::Read).unwrap().val(), 5);
+        assert_eq!(
+            Status::Register::new(0b1_1100).get_signed(Status::Color::Read),
+            7
+        );
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_rw_macro() {
+    fn test_serde() {
         let mut reg = Status::Register::new(0);
-        reg.modify(Status::Dead::Field::checked::<U1>());
-        assert_eq!(reg.read(), 2);
+        reg.modify(Status::Dead::Set + Status::Color::Blue);
+        assert_eq!(
+            serde_json::to_string(&reg).unwrap(),
+            r#"{"On":0,"Dead":1,"Color":"Blue"}"#
+        );
+        reg.modify(Status::Color::Set);
+        assert_eq!(
+            serde_json::to_string(&reg).unwrap(),
+            r#"{"On":0,"Dead":1,"Color":7}"#
+        );
+
+        let copy = reg.extract();
+        let json = serde_json::to_string(&copy).unwrap();
+        let back: crate::ReadOnlyCopy<u8, Status::Register> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.read(), copy.read());
+        assert!(back.is_set(Status::Color::Read));
     }
 
     #[test]
-    fn test_matches_any() {
+    fn test_write_field() {
+        let mut reg = Status::Register::new(0b1110_0011);
+        reg.write_field(Status::Color::Blue);
+        assert_eq!(reg.read(), 0b1110_1011);
+        reg.write_field(Status::Color::Red);
+        assert_eq!(reg.read(), 0b1110_0111);
+        assert_eq!(
+            reg.get_enum(Status::Color::Read),
+            Some(Status::Color::Value::Red)
+        );
+    }
+
+    #[test]
+    fn test_increment() {
+        use crate::Overflow;
+
+        let mut reg = Status::Register::new(0b0001_0001);
+        assert!(!reg.increment(Status::Color::Read, 2, Overflow::Wrap));
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().val(), 6);
+        assert!(reg.increment(Status::Color::Read, 3, Overflow::Wrap));
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().val(), 1);
+        assert!(reg.increment(Status::Color::Read, 9, Overflow::Saturate));
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().val(), 7);
+        assert!(reg.increment(Status::Color::Read, 17, Overflow::Wrap));
+        assert_eq!(reg.get_field(Status::Color::Read).unwrap().val(), 0);
+        assert_eq!(reg.read(), 0b0000_0001);
+    }
+
+    #[test]
+    fn test_overwrite() {
+        let mut reg = Status::Register::new(0b1110_0011);
+        reg.overwrite(Status::Dead::Set + Status::Color::Green);
+        assert_eq!(reg.read(), 0b0000_1110);
+    }
+
+    #[test]
+    fn test_ordered_access() {
+        use core::sync::atomic::Ordering;
+
         let mut reg = Status::Register::new(0);
-        reg.modify(Status::Dead::Set);
-        assert!(reg.matches_any(Status::On::Set + Status::Dead::Set));
-        reg.modify(Status::Dead::Clear);
-        assert!(!reg.matches_any(Status::On::Set + Status::Dead::Set));
+        unsafe { reg.write_ordered(0b0000_1010, Ordering::Release) };
+        assert_eq!(reg.read_ordered(Ordering::Acquire), 0b0000_1010);
+        assert_eq!(reg.read_ordered(Ordering::SeqCst), reg.read());
     }
 
     #[test]
-    fn test_matches_all() {
+    fn test_get_enum() {
         let mut reg = Status::Register::new(0);
-        reg.modify(Status::Dead::Set + Status::On::Set);
-        assert!(reg.matches_all(Status::On::Set + Status::Dead::Set));
-        reg.modify(Status::Dead::Clear);
-        assert!(!reg.matches_all(Status::On::Set + Status::Dead::Set));
+        reg.modify(Status::On::Set + Status::Color::Green);
+        assert_eq!(
+            reg.get_enum(Status::Color::Read),
+            Some(Status::Color::Value::Green)
+        );
+
+        let copy = reg.extract();
+        reg.modify(Status::Color::Set);
+        assert_eq!(reg.get_enum(Status::Color::Read), None);
+        assert_eq!(
+            copy.get_enum(Status::Color::Read),
+            Some(Status::Color::Value::Green)
+        );
     }
 
-    register! {
-        ///  A random number generator
-        #[derive(Debug)]
-        RNG,
-        u8,
-        RO,
-        Fields [
-            /// This field means the RNG is working on generating a
-            /// random number.
-            Working WIDTH(U1) OFFSET(U0),
-            NumWidth WIDTH(U2) OFFSET(U1) [
-                Four = U0,
-                Eight = U1,
-                Sixteen = U2
+    #[test]
+    fn test_read_variant() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Color::Blue);
+        assert_eq!(
+            reg.read_variant(Status::Color::Read),
+            Ok(Status::Color::Value::Blue)
+        );
+
+        // No value of `Color` is named 7.
+        reg.modify(Status::Color::Set);
+        assert_eq!(
+            reg.read_variant(Status::Color::Read),
+            Err(crate::UnknownEncoding { raw: 7 })
+        );
+        assert_eq!(
+            reg.extract().read_variant(Status::Color::Read),
+            Err(crate::UnknownEncoding { raw: 7 })
+        );
+    }
+
+    #[test]
+    fn test_flag() {
+        let mut reg = Status::Register::new(0);
+        assert!(!*reg.flag(Status::Dead::Read));
+        reg.modify(Status::Dead::Set);
+        if !*reg.flag(Status::Dead::Read) {
+            panic!("Dead should be set");
+        }
+        assert!(!*reg.flag(Status::On::Read));
+    }
+
+    #[test]
+    fn test_derive_debug() {
+        extern crate std;
+        use std::format;
+
+        register! {
+            Shown,
+            u8,
+            RW,
+            Derive[Debug],
+            Fields [
+                On WIDTH(U1) OFFSET(U0),
+                Dead WIDTH(U1) OFFSET(U1),
+                Color WIDTH(U3) OFFSET(U2) [
+                    Red = U1,
+                    Blue = U2
+                ]
             ]
-        ]
+        }
+
+        let mut reg = Shown::Register::new(0);
+        reg.modify(Shown::Dead::Set + Shown::Color::Blue);
+        assert_eq!(
+            format!("{:?}", reg),
+            "Shown { On: 0, Dead: 1, Color: Blue(2) }"
+        );
+        reg.modify(Shown::Color::Set);
+        assert_eq!(format!("{:?}", reg), "Shown { On: 0, Dead: 1, Color: 7 }");
     }
 
     #[test]
-    fn test_ro_macro() {
-        let reg = RNG::Register::new(4);
-        let width = reg.get_field(RNG::NumWidth::Read).unwrap();
-        assert_eq!(width, RNG::NumWidth::Sixteen);
+    fn test_batch() {
+        use crate::Batch;
+
+        let mut status = Status::Register::new(0b0000_0011);
+        let mut packed = Status::Register::new(0);
+        Batch::new()
+            .modify(&mut status, Status::On::Clear + Status::Color::Red)
+            .modify(&mut packed, Status::Color::Blue + Status::On::Set)
+            .execute();
+        assert_eq!(status.read(), 0b0000_0110);
+        assert_eq!(packed.read(), 0b0000_1001);
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format() {
+        fn is_format<T: defmt::Format>() {}
+        is_format::<Status::Register>();
+        is_format::<RNG::Register>();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_mock_register() {
+        use crate::mock::{MockAccess, MockRegister};
+        use crate::{Modify, Readable};
+
+        fn revive<R>(reg: &mut R) -> Option<u8>
+        where
+            R: Readable<Width = u8, Register = Status::Register> + Modify<Width = u8>,
+        {
+            if reg.get_field(Status::Dead::Read)?.is_set() {
+                reg.modify(Status::Dead::Clear + Status::On::Set);
+            }
+            reg.get_field(Status::Color::Read).map(|c| c.val())
+        }
+
+        let mut mock = MockRegister::<u8, Status::Register>::new(0b0000_1010);
+        assert_eq!(revive(&mut mock), Some(2));
+        assert_eq!(mock.value(), 0b0000_1001);
+        assert_eq!(
+            mock.accesses(),
+            [
+                MockAccess::Read(0b0000_1010),
+                MockAccess::Read(0b0000_1010),
+                MockAccess::Write(0b0000_1001),
+                MockAccess::Read(0b0000_1001),
+            ]
+        );
+        mock.clear_log();
+        assert!(mock.accesses().is_empty());
+
+        let mut reg = Status::Register::new(0b0000_1010);
+        assert_eq!(revive(&mut reg), Some(2));
+        assert_eq!(reg.read(), 0b0000_1001);
+    }
+
+    #[test]
+    fn test_try_new() {
+        extern crate std;
+        use std::format;
+
+        let color = Status::Color::Field::try_new(5).unwrap();
+        assert_eq!(color.val(), 5);
+        let err = Status::Color::Field::try_new(9).unwrap_err();
+        assert_eq!(
+            err,
+            crate::FieldError {
+                val: 9,
+                lower: 0,
+                upper: 7
+            }
+        );
+        let color = color.set_saturating(200);
+        assert_eq!(color.val(), 7);
+        let err = color.try_set(8).unwrap_err();
+        assert_eq!(err.val, 8);
+
+        assert_eq!(
+            format!("{}", err),
+            "value 8 exceeds the field's upper bound of 7"
+        );
+    }
+
+    #[test]
+    fn test_field_as() {
+        #[derive(Debug, PartialEq)]
+        enum Shade {
+            Warm,
+            Cool,
+            Other(u8),
+        }
+
+        impl From<u8> for Shade {
+            fn from(val: u8) -> Self {
+                match val {
+                    1 | 4 => Shade::Warm,
+                    2 | 3 => Shade::Cool,
+                    val => Shade::Other(val),
+                }
+            }
+        }
+
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::On::Set + Status::Color::Green);
+        assert_eq!(
+            reg.field_as::<Shade, _, _, _, _, _>(Status::Color::Read),
+            Shade::Cool
+        );
+        reg.modify(Status::Color::Set);
+        let shade: Shade = reg.field_as(Status::Color::Read);
+        assert_eq!(shade, Shade::Other(7));
+        assert_eq!(reg.field_as::<u32, _, _, _, _, _>(Status::Color::Read), 7);
+
+        // A field with a lower bound can be read as well.
+        register! {
+            Clock,
+            u8,
+            RW,
+            Fields [
+                Divisor WIDTH(U3) OFFSET(U0) MIN(U1)
+            ]
+        }
+
+        let clock = Clock::Register::new(5);
+        assert_eq!(
+            clock.field_as::<u32, _, _, _, _, _>(Clock::Divisor::Read),
+            5
+        );
+        assert_eq!(
+            clock
+                .extract()
+                .field_as::<u8, _, _, _, _, _>(Clock::Divisor::Read),
+            5
+        );
+    }
+
+    #[test]
+    fn test_min_bound() {
+        extern crate std;
+        use std::format;
+
+        register! {
+            Clock,
+            u8,
+            RW,
+            Fields [
+                Divisor WIDTH(U3) OFFSET(U0) MIN(U1),
+                Enable WIDTH(U1) OFFSET(U3)
+            ]
+        }
+
+        assert!(Clock::Divisor::Field::new(0).is_none());
+        assert_eq!(Clock::Divisor::Field::new(1).unwrap().val(), 1);
+        let err = Clock::Divisor::Field::try_new(0)
+            .map(|f| f.val())
+            .unwrap_err();
+        assert_eq!((err.lower, err.upper), (1, 7));
+        assert_eq!(
+            format!("{}", err),
+            "value 0 is below the field's lower bound of 1"
+        );
+
+        let mut reg = Clock::Register::new(0);
+        assert!(reg.get_field(Clock::Divisor::Read).is_none());
+        reg.modify(Clock::Divisor::Field::checked::<U5>() + Clock::Enable::Set);
+        assert_eq!(reg.read(), 0b1101);
+        assert_eq!(reg.get_field(Clock::Divisor::Read).unwrap().val(), 5);
+        assert!(!reg.is_set(Clock::Divisor::Read));
+        assert!(!reg.is_clear(Clock::Divisor::Read));
+        assert!(Clock::Register::new(0b1000).is_clear(Clock::Divisor::Read));
+        assert!(!reg.extract().is_clear(Clock::Enable::Read));
+
+        assert!(Clock::Divisor::values().map(|f| f.val()).eq(1..=7));
+
+        let divisor = Clock::Divisor::Read.set_saturating(0);
+        assert_eq!(divisor.val(), 1);
+        assert_eq!(divisor.set_saturating(12).val(), 7);
     }
 
     #[test]
@@ -483,4 +4855,66 @@ mod test {
         reg.modify(Status::Dead::Set + Status::Color::Blue + Status::On::Clear);
         assert_eq!(reg.read(), 10);
     }
+
+    #[test]
+    fn test_field_disj_bitor() {
+        let mut reg = Status::Register::new(0);
+        reg.modify(Status::Dead::Set | Status::Color::Blue | Status::On::Clear);
+        assert_eq!(reg.read(), 10);
+
+        let joined = (Status::On::Set | Status::Dead::Set) | Status::Color::Blue;
+        let added = Status::On::Set + Status::Dead::Set + Status::Color::Blue;
+        assert_eq!(
+            (joined.mask(), joined.in_position()),
+            (added.mask(), added.in_position())
+        );
+    }
+
+    #[test]
+    fn test_field_disj_associativity() {
+        register! {
+            Quad,
+            u16,
+            RW,
+            Fields [
+                A WIDTH(U1) OFFSET(U0),
+                B WIDTH(U3) OFFSET(U1),
+                C WIDTH(U4) OFFSET(U4),
+                D WIDTH(U5) OFFSET(U8)
+            ]
+        }
+
+        let parts = || {
+            (
+                Quad::A::Set,
+                Quad::B::Field::checked::<U5>(),
+                Quad::C::Field::checked::<U9>(),
+                Quad::D::Field::checked::<U2>(),
+            )
+        };
+        let expected = (0x1fff, 1 | 5 << 1 | 9 << 4 | 2 << 8);
+        let check = |v: &dyn Positioned<Width = u16>| {
+            assert_eq!((v.mask(), v.in_position()), expected);
+        };
+
+        let (a, b, c, d) = parts();
+        check(&(((a + b) + c) + d));
+        let (a, b, c, d) = parts();
+        check(&((a + (b + c)) + d));
+        let (a, b, c, d) = parts();
+        check(&((a + b) + (c + d)));
+        let (a, b, c, d) = parts();
+        check(&(a + ((b + c) + d)));
+        let (a, b, c, d) = parts();
+        check(&(a + (b + (c + d))));
+
+        let (a, b, c, _) = parts();
+        let left = (a + b) + c;
+        let (a, b, c, _) = parts();
+        let right = a + (b + c);
+        assert_eq!(
+            (left.mask(), left.in_position()),
+            (right.mask(), right.in_position())
+        );
+    }
 }
@@ -0,0 +1,100 @@
+//! An in-memory stand-in for a register, kept when the `mock` feature
+//! is enabled, for testing drivers on the host.
+//!
+//! A `MockRegister` answers the same `Readable` and `Modify` calls as
+//! the register it stands in for, but holds its value in memory and
+//! logs every access, so that a test can assert the sequence of reads
+//! and writes a driver made. Driver code which is generic over those
+//! traits can be handed either one.
+
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::marker::PhantomData;
+use core::ops::{BitAnd, BitOr, Not, Shr};
+
+use crate::{Modify, Positioned, Readable};
+
+/// A single access to a `MockRegister`, and the value read or written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MockAccess<W> {
+    /// The register was read, and held the value.
+    Read(W),
+    /// The value was written to the register.
+    Write(W),
+}
+
+/// `MockRegister` stands in for the register `R`, whose width is `W`.
+/// Fields of `R` may be read from and written to it just as they would
+/// be to `R`. Unlike `R`'s `modify`, its `modify` knows nothing of
+/// write-one-to-clear fields, and writes back what it read of them.
+pub struct MockRegister<W, R> {
+    val: Cell<W>,
+    log: RefCell<Vec<MockAccess<W>>>,
+    _reg_type: PhantomData<R>,
+}
+
+impl<W: Copy, R> MockRegister<W, R> {
+    /// `new` constructs a mock register holding `init`, with an empty
+    /// log.
+    pub fn new(init: W) -> Self {
+        MockRegister {
+            val: Cell::new(init),
+            log: RefCell::new(Vec::new()),
+            _reg_type: PhantomData,
+        }
+    }
+
+    /// `value` returns what the register holds without logging a read.
+    pub fn value(&self) -> W {
+        self.val.get()
+    }
+
+    /// `write` sets the value of the whole register, logging the write.
+    /// Unlike a real register's, it is safe, since there is no hardware
+    /// behind it.
+    pub fn write(&mut self, val: W) {
+        self.val.set(val);
+        self.log.borrow_mut().push(MockAccess::Write(val));
+    }
+
+    /// `accesses` returns every access logged so far, oldest first.
+    pub fn accesses(&self) -> Vec<MockAccess<W>> {
+        self.log.borrow().clone()
+    }
+
+    /// `clear_log` forgets every logged access.
+    pub fn clear_log(&mut self) {
+        self.log.borrow_mut().clear();
+    }
+}
+
+impl<W, R> Readable for MockRegister<W, R>
+where
+    W: Copy + PartialOrd + BitAnd<Output = W> + Shr<Output = W>,
+{
+    type Width = W;
+    type Register = R;
+
+    fn read(&self) -> W {
+        let val = self.val.get();
+        self.log.borrow_mut().push(MockAccess::Read(val));
+        val
+    }
+}
+
+impl<W, R> Modify for MockRegister<W, R>
+where
+    W: Copy
+        + PartialOrd
+        + BitAnd<Output = W>
+        + BitOr<Output = W>
+        + Not<Output = W>
+        + Shr<Output = W>,
+{
+    type Width = W;
+
+    fn modify<V: Positioned<Width = W>>(&mut self, val: V) {
+        let old = self.read();
+        self.write((old & !val.mask()) | val.in_position());
+    }
+}
@@ -1,48 +1,138 @@
+use core::fmt;
 use core::marker::PhantomData;
-use core::ops::{Add, BitAnd, BitOr, Shl, Shr};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Deref, DerefMut, Not, Shl, Shr};
 
-use typenum::consts::{True, U0};
+use typenum::consts::{True, U0, U1};
 use typenum::{IsGreater, IsGreaterOrEqual, IsLessOrEqual, Unsigned};
 
-use super::bounds::{Bounded, ReifyTo};
+use super::bounds::{Bounded, Reifier, ReifyTo};
 
+/// `ReadOnlyCopy` is a register's value as captured by `extract`. It
+/// answers the same queries as the register itself, but against the
+/// captured value, so any number of them cost a single read and agree
+/// with one another.
 pub struct ReadOnlyCopy<W, R>(pub W, pub PhantomData<R>);
 
 impl<W, R> ReadOnlyCopy<W, R>
 where
     W: Copy + Clone + PartialOrd + BitAnd<W, Output = W> + Shr<W, Output = W> + Default,
 {
-    pub fn get_field<M: Unsigned, O: Unsigned, U: Unsigned>(
+    /// `get_field` takes a field and sets the value of that field to
+    /// its value in the copy.
+    pub fn get_field<M, O, U, L, Own>(
         &self,
-        f: Field<W, M, O, U, R>,
-    ) -> Option<Field<W, M, O, U, R>>
+        f: Field<W, M, O, U, Own, L>,
+    ) -> Option<Field<W, M, O, U, Own, L>>
     where
-        U: IsGreater<U0, Output = True> + ReifyTo<W>,
-        M: ReifyTo<W>,
-        O: ReifyTo<W>,
-        U0: ReifyTo<W>,
+        Own: BelongsTo<R>,
+        U: Unsigned + IsGreater<U0, Output = True> + IsGreater<L, Output = True> + ReifyTo<W>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned + ReifyTo<W>,
+        L: ReifyTo<W>,
     {
-        f.set((self.0 & M::reify()) >> O::reify())
+        f.set((self.0 >> O::reify()) & U::reify())
     }
 
+    /// `get_field_by` is `get_field`, but the field is named by its type,
+    /// e.g. `copy.get_field_by::<Status::Color::Field>()`, so no instance
+    /// of it need be handed over.
+    pub fn get_field_by<F: FieldOf<W, R>>(&self) -> Option<F> {
+        F::extract(self.0)
+    }
+
+    /// `get_enum` decodes a field which declares named values, as
+    /// captured in the copy, into the name of the value it holds, or
+    /// `None` if that value has no name.
+    pub fn get_enum<M, O, U, L, Own>(&self, _: Field<W, M, O, U, Own, L>) -> Option<R::Value>
+    where
+        Own: BelongsTo<R>,
+        R: Decode<W, M, O>,
+        U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<W>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned + ReifyTo<W>,
+    {
+        R::decode((self.0 >> O::reify()) & U::reify())
+    }
+
+    /// `read_variant` is `get_enum`, but a value with no name is an
+    /// error carrying the raw value.
+    pub fn read_variant<M, O, U, L, Own>(
+        &self,
+        _: Field<W, M, O, U, Own, L>,
+    ) -> Result<R::Value, UnknownEncoding<W>>
+    where
+        Own: BelongsTo<R>,
+        R: Decode<W, M, O>,
+        U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<W>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned + ReifyTo<W>,
+    {
+        let raw = (self.0 >> O::reify()) & U::reify();
+        R::decode(raw).ok_or(UnknownEncoding { raw })
+    }
+
+    /// `read` returns the captured value as a `W`.
     pub fn read(&self) -> W {
         self.0
     }
 
+    /// `extract` copies the copy.
     pub fn extract(&self) -> Self {
         ReadOnlyCopy(self.0, PhantomData)
     }
 
-    pub fn is_set<M: Unsigned, O: Unsigned, U: Unsigned>(&self, _: Field<W, M, O, U, R>) -> bool
+    /// `is_set` takes a field and returns true if that field's value is
+    /// equal to its upper bound or not.
+    pub fn is_set<M, O, U, L, Own>(&self, _: Field<W, M, O, U, Own, L>) -> bool
+    where
+        Own: BelongsTo<R>,
+        U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<W>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned + ReifyTo<W>,
+    {
+        ((self.0 >> O::reify()) & U::reify()) == U::reify()
+    }
+
+    /// `is_clear` takes a field and returns whether or not all of that
+    /// field's bits are zero, the dual of `is_set`. For a field with a
+    /// lower bound, it still tests the bits against zero, not against
+    /// the bound.
+    pub fn is_clear<M, O, U, L, Own>(&self, _: Field<W, M, O, U, Own, L>) -> bool
+    where
+        Own: BelongsTo<R>,
+        U: Unsigned + IsGreater<U0, Output = True>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned,
+    {
+        (self.0 & M::reify()) == W::default()
+    }
+
+    /// `flag` reads a single-bit field as a `Flag`, which dereferences
+    /// to whether or not the bit is set.
+    pub fn flag<M, O, Own>(&self, _: Field<W, M, O, U1, Own>) -> Flag
+    where
+        Own: BelongsTo<R>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned,
+    {
+        Flag((self.0 & M::reify()) != W::default())
+    }
+
+    /// `field_as` reads a field and converts its value into `T`, such
+    /// as a domain type which implements `From<W>`.
+    pub fn field_as<T, M, O, U, L, Own>(&self, _: Field<W, M, O, U, Own, L>) -> T
     where
-        U: IsGreater<U0, Output = True>,
-        U: ReifyTo<W>,
-        M: ReifyTo<W>,
-        O: ReifyTo<W>,
+        Own: BelongsTo<R>,
+        T: From<W>,
+        U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<W>,
+        M: Unsigned + ReifyTo<W>,
+        O: Unsigned + ReifyTo<W>,
     {
-        ((self.0 & M::reify()) >> O::reify()) == U::reify()
+        T::from((self.0 >> O::reify()) & U::reify())
     }
 
+    /// `matches_any` returns whether or not any of the given fields
+    /// match those fields values inside the copy.
     pub fn matches_any<V: Positioned<Width = W>>(&self, val: V) -> bool {
         (val.in_position() & self.0) != W::default()
     }
@@ -52,33 +142,232 @@ where
     pub fn matches_all<V: Positioned<Width = W>>(&self, val: V) -> bool {
         (val.in_position() & self.0) == val.in_position()
     }
+
+    /// `matches_none` returns whether or not none of the given fields'
+    /// bits are set inside the register.
+    pub fn matches_none<V: Positioned<Width = W>>(&self, val: V) -> bool {
+        (val.in_position() & self.0) == W::default()
+    }
+}
+
+impl<W, R> ReadOnlyCopy<W, R>
+where
+    W: Copy + BitXor<W, Output = W>,
+{
+    /// `diff` returns the bits which differ between this snapshot and
+    /// `other`.
+    pub fn diff(&self, other: &Self) -> W {
+        self.0 ^ other.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<W: serde::Serialize, R> serde::Serialize for ReadOnlyCopy<W, R> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, W: serde::Deserialize<'de>, R> serde::Deserialize<'de> for ReadOnlyCopy<W, R> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        W::deserialize(deserializer).map(|val| ReadOnlyCopy(val, PhantomData))
+    }
+}
+
+/// `BitBand` is the bit-band alias of a single bit, as found on Cortex-M3
+/// and M4 parts. Each bit in a bit-band region is mirrored by a word in its
+/// alias region, and writing that word sets or clears the bit alone, in a
+/// single bus access which can't be torn by an interrupt.
+///
+/// Single-bit fields get a `bit_band` constructor when the `bitband`
+/// feature is enabled.
+#[cfg(feature = "bitband")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitBand {
+    alias: usize,
+}
+
+#[cfg(feature = "bitband")]
+impl BitBand {
+    /// The start of the SRAM bit-band region.
+    pub const SRAM_BASE: usize = 0x2000_0000;
+    /// The start of the SRAM bit-band alias region.
+    pub const SRAM_ALIAS: usize = 0x2200_0000;
+    /// The start of the peripheral bit-band region.
+    pub const PERIPHERAL_BASE: usize = 0x4000_0000;
+    /// The start of the peripheral bit-band alias region.
+    pub const PERIPHERAL_ALIAS: usize = 0x4200_0000;
+
+    /// `new` computes the alias of bit `bit` of the word at `addr`,
+    /// which lies in the bit-band region starting at `base` whose alias
+    /// region starts at `alias_base`.
+    pub const fn new(base: usize, alias_base: usize, addr: usize, bit: usize) -> Self {
+        BitBand {
+            alias: alias_base + (addr - base) * 32 + bit * 4,
+        }
+    }
+
+    /// `alias` returns the address of the bit's alias word.
+    pub const fn alias(&self) -> usize {
+        self.alias
+    }
+
+    /// `set_atomic` sets the bit with a single write to its alias.
+    ///
+    /// # Safety
+    ///
+    /// The alias must have been computed from a register which lies in
+    /// a bit-band region, on a part which implements bit-banding.
+    pub unsafe fn set_atomic(&self) {
+        core::ptr::write_volatile(self.alias as *mut u32, 1);
+    }
+
+    /// `clear_atomic` clears the bit with a single write to its alias.
+    ///
+    /// # Safety
+    ///
+    /// The alias must have been computed from a register which lies in
+    /// a bit-band region, on a part which implements bit-banding.
+    pub unsafe fn clear_atomic(&self) {
+        core::ptr::write_volatile(self.alias as *mut u32, 0);
+    }
+}
+
+/// `Flag` is the state of a single-bit field, as read by a register's
+/// `flag` method. It dereferences to whether or not the bit is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flag(pub bool);
+
+impl Deref for Flag {
+    type Target = bool;
+
+    fn deref(&self) -> &bool {
+        &self.0
+    }
+}
+
+/// `Overflow` chooses what a register's `increment` does with a
+/// count which would pass one of the field's bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wrap around to the other bound, like an odometer.
+    Wrap,
+    /// Stop at the bound which was passed.
+    Saturate,
+}
+
+/// `NamedValue` is how an enum-like field's value is shown by the
+/// generated `Debug` implementations: by the name of its value and the
+/// raw value itself, e.g. `Blue(2)`, or just the raw value when it has
+/// no name.
+#[doc(hidden)]
+pub struct NamedValue<V, W>(pub Option<V>, pub W);
+
+impl<V: fmt::Debug, W: fmt::Debug> fmt::Debug for NamedValue<V, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(name) => write!(f, "{:?}({:?})", name, self.1),
+            None => write!(f, "{:?}", self.1),
+        }
+    }
+}
+
+/// `FieldError` is returned when a field is given a value outside of
+/// its bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldError<W> {
+    /// The value which was rejected.
+    pub val: W,
+    /// The smallest value the field can hold.
+    pub lower: W,
+    /// The largest value the field can hold.
+    pub upper: W,
+}
+
+impl<W: fmt::Display + PartialOrd> fmt::Display for FieldError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.val < self.lower {
+            write!(
+                f,
+                "value {} is below the field's lower bound of {}",
+                self.val, self.lower
+            )
+        } else {
+            write!(
+                f,
+                "value {} exceeds the field's upper bound of {}",
+                self.val, self.upper
+            )
+        }
+    }
+}
+
+/// `InitError` is returned by a register's `try_new` when the value it
+/// was given isn't one the register's fields allow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitError<W> {
+    /// The named field's value lies outside of its bounds.
+    OutOfBounds(&'static str, FieldError<W>),
+    /// The named reserved field holds something other than zero.
+    Reserved(&'static str),
+    /// These bits, which belong to no field, are set.
+    Undefined(W),
+}
+
+impl<W: fmt::Display + fmt::LowerHex + PartialOrd> fmt::Display for InitError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitError::OutOfBounds(field, err) => write!(f, "field {}: {}", field, err),
+            InitError::Reserved(field) => write!(f, "reserved field {} is not zero", field),
+            InitError::Undefined(bits) => {
+                write!(f, "bits {:#x} belong to no field, but are set", bits)
+            }
+        }
+    }
+}
+
+/// `UnknownEncoding` is returned when a field holds a value which none
+/// of its named values stands for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownEncoding<W> {
+    /// The value the field holds.
+    pub raw: W,
+}
+
+impl<W: fmt::Display> fmt::Display for UnknownEncoding<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value {} has no name in the field", self.raw)
+    }
 }
 
 /// A field in a register parameterized by its mask, offset, and upper
-/// bound. To construct a field, its `val` must be ⩽ `U::U32`.
+/// bound. To construct a field, its `val` must be ⩽ `U::U32`, and ⩾
+/// its lower bound `L`, which is zero unless the field declares a
+/// `MIN`.
 ///
 /// It uses these type-level numbers so that the mask and offset can
 /// be constant.
 #[derive(Debug)]
-pub struct Field<W, M, O, U, R>
+pub struct Field<W, M, O, U, R, L = U0>
 where
     U: IsGreater<U0, Output = True>,
 {
-    val: Bounded<W, U0, U>,
+    val: Bounded<W, L, U>,
     _mask: PhantomData<M>,
     _offset: PhantomData<O>,
     _reg_type: PhantomData<R>,
 }
 
-impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R> Field<W, M, O, U, R>
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Field<W, M, O, U, R, L>
 where
     U: IsGreater<U0, Output = True> + ReifyTo<W>,
     W: Copy + Clone + PartialOrd + BitAnd<W, Output = W> + Shr<W, Output = W> + Default,
-    U: ReifyTo<W>,
-    U0: ReifyTo<W>,
+    U: ReifyTo<W> + IsGreater<L, Output = True>,
+    L: ReifyTo<W>,
 {
-    /// New returns a `Some(Field)` if the given value is less than or equal to
-    /// its upper bound, otherwise it returns `None`.
+    /// New returns a `Some(Field)` if the given value lies within its
+    /// bounds, otherwise it returns `None`.
     pub fn new(val: W) -> Option<Self> {
         Bounded::new(val).map(|val| Self {
             val,
@@ -89,7 +378,7 @@ where
     }
 
     /// `set` takes an existing field sets its value to `val`. If val
-    /// is _not_ ⩽ `U`, it returns `None`.
+    /// is _not_ ⩽ `U` and ⩾ `L`, it returns `None`.
     pub fn set(mut self, val: W) -> Option<Self> {
         Bounded::new(val).map(|val| {
             self.val = val;
@@ -97,6 +386,50 @@ where
         })
     }
 
+    /// `set_saturating` is `set`, but a value which lies outside the
+    /// field's bounds is clamped to the nearer of them rather than
+    /// rejected.
+    pub fn set_saturating(mut self, val: W) -> Self {
+        let (lower, upper) = (L::reify(), U::reify());
+        let val = if val < lower {
+            lower
+        } else if val > upper {
+            upper
+        } else {
+            val
+        };
+        if let Some(val) = Bounded::new(val) {
+            self.val = val;
+        }
+        self
+    }
+
+    /// `try_new` is `new`, but when the value lies outside the field's
+    /// bounds it says so with a `FieldError` rather than `None`.
+    pub fn try_new(val: W) -> Result<Self, FieldError<W>> {
+        Self::new(val).ok_or(FieldError {
+            val,
+            lower: L::reify(),
+            upper: U::reify(),
+        })
+    }
+
+    /// `try_set` is `set`, but when the value lies outside the field's
+    /// bounds it says so with a `FieldError` rather than `None`.
+    pub fn try_set(self, val: W) -> Result<Self, FieldError<W>> {
+        self.set(val).ok_or(FieldError {
+            val,
+            lower: L::reify(),
+            upper: U::reify(),
+        })
+    }
+}
+
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Field<W, M, O, U, R, L>
+where
+    U: IsGreater<U0, Output = True> + ReifyTo<W>,
+    W: Copy + PartialEq,
+{
     /// `val` retrieves the value from the field.
     pub fn val(&self) -> W {
         self.val.val
@@ -107,51 +440,195 @@ where
     pub fn is_set(&self) -> bool {
         self.val.val == U::reify()
     }
+
+    /// `raw_eq` returns whether or not the field holds the same value as
+    /// `other`, a field laid out the same way but belonging to another
+    /// register, such as a shadow copy of a live one. Unlike `==`, it
+    /// doesn't require the two to belong to the same register.
+    pub fn raw_eq<R2, L2>(&self, other: &Field<W, M, O, U, R2, L2>) -> bool {
+        self.val.val == other.val.val
+    }
+}
+
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Field<W, M, O, U, R, L>
+where
+    U: IsGreater<U0, Output = True>,
+    W: Copy,
+    R: Decode<W, M, O>,
+{
+    /// `decode` returns the named value the field holds, or `None` if
+    /// its value has no name.
+    pub fn decode(&self) -> Option<R::Value> {
+        R::decode(self.val.val)
+    }
 }
 
 macro_rules! checked {
     ($num_type:ty) => {
-        impl<M: Unsigned, O: Unsigned, U: Unsigned, R> Field<$num_type, M, O, U, R>
+        impl<M: Unsigned, O: Unsigned, U: Unsigned, R, L> Field<$num_type, M, O, U, R, L>
         where
             U: IsGreater<U0, Output = True>,
         {
             /// `checked` is a compile-time checked constructor for a
-            /// `Field`. Its `V` parameter must be ⩽ `U`; if it is not, the
-            /// program will fail to typecheck.
-            pub const fn checked<V: Unsigned>() -> Self
+            /// `Field`. Its `V` parameter must be ⩽ `U` and ⩾ `L`; if it
+            /// is not, the program will fail to typecheck.
+            pub const fn checked<V>() -> Self
             where
-                V: IsLessOrEqual<U, Output = True>,
-                V: IsGreaterOrEqual<U0, Output = True>,
+                V: Unsigned + IsLessOrEqual<U, Output = True>,
+                V: IsGreaterOrEqual<L, Output = True>,
             {
                 Self {
-                    val: Bounded::<$num_type, U0, U>::checked::<V>(),
+                    val: Bounded::<$num_type, L, U>::checked::<V>(),
                     _offset: PhantomData,
                     _mask: PhantomData,
                     _reg_type: PhantomData,
                 }
             }
+
+            /// `zero` is a compile-time constructor for a `Field` whose
+            /// value is zero, like a field module's `Clear`.
+            pub const fn zero() -> Self
+            where
+                U0: IsLessOrEqual<U, Output = True> + IsGreaterOrEqual<L, Output = True>,
+            {
+                Self::checked::<U0>()
+            }
+
+            /// `max` is a compile-time constructor for a `Field` whose
+            /// value is its upper bound, like a field module's `Set`.
+            pub const fn max() -> Self
+            where
+                U: IsLessOrEqual<U, Output = True> + IsGreaterOrEqual<L, Output = True>,
+            {
+                Self::checked::<U>()
+            }
+
+            /// `packed` is the field's value in position within its
+            /// register, as `Positioned::in_position` gives it, but usable
+            /// in `const` contexts. OR-ing several together builds a whole
+            /// register value at compile time.
+            pub const fn packed(&self) -> $num_type {
+                self.val.val << Reifier::<O, $num_type>::reify()
+            }
+        }
+
+        impl<M: Unsigned, O: Unsigned, U: Unsigned, R, L: Unsigned> Field<$num_type, M, O, U, R, L>
+        where
+            U: IsGreater<U0, Output = True> + IsGreater<L, Output = True>,
+        {
+            /// `from_percent` scales a percentage, from 0 to 100, into the
+            /// field's range, rounding down, so that 50 percent of an
+            /// 8-bit field is 127. It returns `None` if `pct` is over 100
+            /// or the scaled value falls below the field's lower bound.
+            pub fn from_percent(pct: u8) -> Option<Self> {
+                if pct > 100 {
+                    return None;
+                }
+                let (upper, pct) = (U::U128, pct as u128);
+                // Split so that a full 128-bit field can't overflow.
+                Self::new((upper / 100 * pct + upper % 100 * pct / 100) as $num_type)
+            }
         }
     };
 }
 
-checked!(u8);
-checked!(u16);
-checked!(u32);
-checked!(u64);
-checked!(usize);
+widths!(checked);
 
-impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R> PartialEq<Field<W, M, O, U, R>>
-    for Field<W, M, O, U, R>
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> PartialEq<Field<W, M, O, U, R, L>>
+    for Field<W, M, O, U, R, L>
 where
     U: IsGreater<U0, Output = True> + ReifyTo<W>,
     W: Copy + Clone + PartialOrd + BitAnd<W, Output = W> + Shr<W, Output = W> + Default,
-    U0: ReifyTo<W>,
+    L: ReifyTo<W>,
 {
-    fn eq(&self, rhs: &Field<W, M, O, U, R>) -> bool {
+    fn eq(&self, rhs: &Field<W, M, O, U, R, L>) -> bool {
         self.val() == rhs.val()
     }
 }
 
+/// `Decode` is implemented by a register for each of its fields which
+/// declares named values, identified by the field's mask `M` and offset
+/// `O`. It maps the field's raw value to the enum of those names, and is
+/// what lies behind `Field::decode`.
+///
+/// *Note*: You probably don't want to implement this directly; `register!`
+/// does so for you.
+pub trait Decode<W, M, O> {
+    type Value;
+    fn decode(val: W) -> Option<Self::Value>;
+}
+
+/// `FieldOf` is implemented by every field of the register `R`, so that
+/// a field can be read by naming its type alone, as in
+/// `copy.get_field_by::<Status::Color::Field>()`, rather than by handing
+/// over an instance of it.
+pub trait FieldOf<W, R>: Sized {
+    /// `extract` pulls the field out of the whole register's value
+    /// `val`, or returns `None` if its value there is out of bounds.
+    fn extract(val: W) -> Option<Self>;
+}
+
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L, Own> FieldOf<W, R>
+    for Field<W, M, O, U, Own, L>
+where
+    Own: BelongsTo<R>,
+    W: Copy + PartialOrd + BitAnd<W, Output = W> + Shr<W, Output = W> + Default,
+    U: IsGreater<U0, Output = True> + IsGreater<L, Output = True> + ReifyTo<W>,
+    O: ReifyTo<W>,
+    L: ReifyTo<W>,
+{
+    fn extract(val: W) -> Option<Self> {
+        Self::new((val >> O::reify()) & U::reify())
+    }
+}
+
+/// `Writable` marks the registers whose fields may be written: only a
+/// field of a `Writable` register is `Positioned`, or can be joined to
+/// others with `+`. Every register generated by `register!` is
+/// `Writable`, while its reserved and read-only fields belong to
+/// `Reserved<Register>` and `ReadOnly<Register>` instead, so that handing
+/// one to `modify` fails to typecheck.
+///
+/// *Note*: You probably don't want to implement this directly; `register!`
+/// does so for you.
+pub trait Writable {}
+
+/// `Reserved<R>` stands in for the register `R` in the type of a field
+/// declared `ACCESS(RESERVED)`. It isn't `Writable`, so the field can be
+/// named but never written.
+pub struct Reserved<R>(PhantomData<R>);
+
+impl<W, M, O, R: Decode<W, M, O>> Decode<W, M, O> for Reserved<R> {
+    type Value = R::Value;
+
+    fn decode(val: W) -> Option<Self::Value> {
+        R::decode(val)
+    }
+}
+
+/// `ReadOnly<R>` stands in for the register `R` in the type of a field
+/// declared `ACCESS(RO)`. Like `Reserved<R>`, it isn't `Writable`, so the
+/// field can't be written, but it `BelongsTo<R>`, so the field can still
+/// be read from `R`.
+pub struct ReadOnly<R>(PhantomData<R>);
+
+impl<W, M, O, R: Decode<W, M, O>> Decode<W, M, O> for ReadOnly<R> {
+    type Value = R::Value;
+
+    fn decode(val: W) -> Option<Self::Value> {
+        R::decode(val)
+    }
+}
+
+/// `BelongsTo<R>` is implemented by the types which may stand for the
+/// register `R` in the type of a field read from it: `R` itself, and
+/// `ReadOnly<R>`.
+pub trait BelongsTo<R> {}
+
+impl<R> BelongsTo<R> for R {}
+
+impl<R> BelongsTo<R> for ReadOnly<R> {}
+
 /// `Positioned` is a trait which is used to represent a value, be it
 /// a `Field` or simply a `u32`, as its value were it to be _in
 /// position_ in its register.
@@ -167,8 +644,9 @@ pub trait Positioned {
     fn in_position(&self) -> Self::Width;
 }
 
-impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R> Positioned for Field<W, M, O, U, R>
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Positioned for Field<W, M, O, U, R, L>
 where
+    R: Writable,
     U: IsGreater<U0, Output = True> + ReifyTo<W>,
     W: Copy
         + Clone
@@ -178,7 +656,7 @@ where
         + Default
         + Shl<W, Output = W>,
     M: ReifyTo<W>,
-    U0: ReifyTo<W>,
+    L: ReifyTo<W>,
     O: ReifyTo<W>,
 {
     type Width = W;
@@ -194,14 +672,103 @@ where
     }
 }
 
+/// `FieldDescriptor` describes one field of a register, as listed by
+/// the register's `field_descriptors`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldDescriptor<W> {
+    /// The field's name, as given to `register!`.
+    pub name: &'static str,
+    /// The bit at which the field starts.
+    pub offset: u32,
+    /// The number of bits the field spans.
+    pub width: u32,
+    /// The field's mask, in position.
+    pub mask: W,
+}
+
+/// `DynField` is a field whose mask, offset, and upper bound are only
+/// known at runtime, such as those read out of a table by a generic
+/// register editor. It trades the compile-time checks of `Field` for
+/// flexibility; its value is instead checked against `upper` when it
+/// is constructed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynField<W> {
+    mask: W,
+    offset: W,
+    upper: W,
+    val: W,
+}
+
+impl<W> DynField<W>
+where
+    W: Copy + PartialOrd,
+{
+    /// `new` returns a `Some(DynField)` if `val` is less than or equal
+    /// to `upper`, otherwise it returns `None`.
+    pub fn new(mask: W, offset: W, upper: W, val: W) -> Option<Self> {
+        if val <= upper {
+            Some(DynField {
+                mask,
+                offset,
+                upper,
+                val,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// `val` retrieves the value from the field.
+    pub fn val(&self) -> W {
+        self.val
+    }
+
+    /// `upper` retrieves the field's upper bound.
+    pub fn upper(&self) -> W {
+        self.upper
+    }
+}
+
+impl<W> Positioned for DynField<W>
+where
+    W: Copy + BitAnd<W, Output = W> + Shl<W, Output = W>,
+{
+    type Width = W;
+
+    fn mask(&self) -> W {
+        self.mask
+    }
+
+    /// Bits which would land outside of `mask` are dropped so that a
+    /// mismatched `upper` can never clobber a neighboring field.
+    fn in_position(&self) -> W {
+        (self.val << self.offset) & self.mask
+    }
+}
+
 /// `FieldDisj` is short for _Field Disjunction_. It is a type which
 /// constitutes the intermediate result of the summing, or disjunct of
-/// two fields. It is not a type which one should use directly.
+/// two fields. It is rarely named directly, except to start from
+/// `FieldDisj::empty()` when folding any number of fields into one
+/// write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FieldDisj<W> {
     mask: W,
     val: W,
 }
 
+impl<W: Default> FieldDisj<W> {
+    /// `empty` is the disjunction of no fields, whose mask and value
+    /// are both zero. Adding a field to it yields that field alone, so
+    /// it can seed a fold over a run of fields.
+    pub fn empty() -> Self {
+        FieldDisj {
+            mask: W::default(),
+            val: W::default(),
+        }
+    }
+}
+
 impl<W: Copy> Positioned for FieldDisj<W> {
     type Width = W;
 
@@ -221,12 +788,16 @@ impl<
         LO: Unsigned,
         LU: Unsigned,
         LR,
+        LL,
         RM: Unsigned,
         RO: Unsigned,
         RU: Unsigned,
         RR,
-    > Add<Field<W, RM, RO, RU, RR>> for Field<W, LM, LO, LU, LR>
+        RL,
+    > Add<Field<W, RM, RO, RU, RR, RL>> for Field<W, LM, LO, LU, LR, LL>
 where
+    LR: Writable,
+    RR: Writable,
     LU: IsGreater<U0, Output = True> + ReifyTo<W>,
     RU: IsGreater<U0, Output = True> + ReifyTo<W>,
     RO: ReifyTo<W>,
@@ -239,13 +810,14 @@ where
         + Default
         + Shl<W, Output = W>
         + BitOr<W, Output = W>,
-    U0: ReifyTo<W>,
+    LL: ReifyTo<W>,
+    RL: ReifyTo<W>,
     LM: BitOr<RM>,
     <LM as BitOr<RM>>::Output: ReifyTo<W>,
 {
     type Output = FieldDisj<W>;
 
-    fn add(self, rhs: Field<W, RM, RO, RU, RR>) -> Self::Output {
+    fn add(self, rhs: Field<W, RM, RO, RU, RR, RL>) -> Self::Output {
         FieldDisj {
             val: (self.val() << LO::reify()) | (rhs.val() << RO::reify()),
             mask: <LM as BitOr<RM>>::Output::reify(),
@@ -255,8 +827,9 @@ where
 
 // Add where the rhs is a `FieldDisj`. This is necessary because I do
 // not know which direction the compiler will associate `+`.
-impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R> Add<FieldDisj<W>> for Field<W, M, O, U, R>
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Add<FieldDisj<W>> for Field<W, M, O, U, R, L>
 where
+    R: Writable,
     U: IsGreater<U0, Output = True> + ReifyTo<W>,
     W: Copy
         + Clone
@@ -266,7 +839,7 @@ where
         + Default
         + Shl<W, Output = W>
         + BitOr<W, Output = W>,
-    U0: ReifyTo<W>,
+    L: ReifyTo<W>,
     O: ReifyTo<W>,
     M: ReifyTo<W>,
 {
@@ -282,8 +855,9 @@ where
 
 // Add where the lhs is a `FieldDisj`. This is necessary because I do
 // not know which direction the compiler will associate `+`.
-impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R> Add<Field<W, M, O, U, R>> for FieldDisj<W>
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L> Add<Field<W, M, O, U, R, L>> for FieldDisj<W>
 where
+    R: Writable,
     U: IsGreater<U0, Output = True> + ReifyTo<W>,
     W: Copy
         + Clone
@@ -293,13 +867,13 @@ where
         + Default
         + Shl<W, Output = W>
         + BitOr<W, Output = W>,
-    U0: ReifyTo<W>,
+    L: ReifyTo<W>,
     O: ReifyTo<W>,
     M: ReifyTo<W>,
 {
     type Output = FieldDisj<W>;
 
-    fn add(self, rhs: Field<W, M, O, U, R>) -> Self::Output {
+    fn add(self, rhs: Field<W, M, O, U, R, L>) -> Self::Output {
         FieldDisj {
             val: self.val | (rhs.val() << O::reify()),
             mask: self.mask | M::reify(),
@@ -307,6 +881,591 @@ where
     }
 }
 
+// Add where both lhs and rhs are `FieldDisj`s, as when two groups of
+// fields are parenthesized.
+impl<W> Add<FieldDisj<W>> for FieldDisj<W>
+where
+    W: Copy + BitOr<W, Output = W>,
+{
+    type Output = FieldDisj<W>;
+
+    fn add(self, rhs: FieldDisj<W>) -> Self::Output {
+        FieldDisj {
+            val: self.val | rhs.val,
+            mask: self.mask | rhs.mask,
+        }
+    }
+}
+
+// Add where the rhs is a `DynField`, so that fields only known at
+// runtime can be folded into a single write.
+impl<W> Add<DynField<W>> for FieldDisj<W>
+where
+    W: Copy + BitAnd<W, Output = W> + BitOr<W, Output = W> + Shl<W, Output = W>,
+{
+    type Output = FieldDisj<W>;
+
+    fn add(self, rhs: DynField<W>) -> Self::Output {
+        FieldDisj {
+            val: self.val | rhs.in_position(),
+            mask: self.mask | rhs.mask(),
+        }
+    }
+}
+
+// `|` joins fields just as `+` does, for those who think of it as the
+// bitwise or it is.
+impl<W, M: Unsigned, O: Unsigned, U: Unsigned, R, L, Rhs> BitOr<Rhs> for Field<W, M, O, U, R, L>
+where
+    U: IsGreater<U0, Output = True>,
+    Self: Add<Rhs, Output = FieldDisj<W>>,
+{
+    type Output = FieldDisj<W>;
+
+    fn bitor(self, rhs: Rhs) -> Self::Output {
+        Add::add(self, rhs)
+    }
+}
+
+impl<W, Rhs> BitOr<Rhs> for FieldDisj<W>
+where
+    Self: Add<Rhs, Output = FieldDisj<W>>,
+{
+    type Output = FieldDisj<W>;
+
+    fn bitor(self, rhs: Rhs) -> Self::Output {
+        Add::add(self, rhs)
+    }
+}
+
+/// `RegisterArray` is a run of `N` identical registers, such as the
+/// control registers of a DMA controller's channels, laid out back to
+/// back. It has the layout of `[R; N]`, so it can stand in for one in a
+/// `#[repr(C)]` register block, and its accessors check the index so
+/// that no access strays past the end of the run:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// # use bounded_registers::RegisterArray;
+/// register! {
+///     ChannelCtrl,
+///     u32,
+///     RW,
+///     Fields [
+///         Enable WIDTH(U1) OFFSET(U0)
+///     ]
+/// }
+///
+/// #[repr(C)]
+/// pub struct DmaBlock {
+///     status: u32,
+///     channels: RegisterArray<ChannelCtrl::Register, 4>,
+/// }
+///
+/// fn main() {
+///     let mut x = [0_u32; 5];
+///     let block = unsafe { &mut *(&mut x as *mut [u32; 5] as *mut DmaBlock) };
+///
+///     block.channels.get_mut(2).unwrap().modify(ChannelCtrl::Enable::Set);
+///     assert!(block.channels.get_mut(4).is_none());
+///     assert_eq!(x[3], 1);
+/// }
+/// ```
+#[repr(transparent)]
+pub struct RegisterArray<R, const N: usize>([R; N]);
+
+impl<R, const N: usize> RegisterArray<R, N> {
+    /// `get` returns the register at `idx`, or `None` if `idx` is out
+    /// of bounds.
+    pub fn get(&self, idx: usize) -> Option<&R> {
+        self.0.get(idx)
+    }
+
+    /// `get_mut` returns the register at `idx`, or `None` if `idx` is
+    /// out of bounds.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut R> {
+        self.0.get_mut(idx)
+    }
+
+    /// `len` returns the number of registers in the array, `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// `is_empty` returns whether or not the array holds no registers.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// `iter` walks the registers in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, R> {
+        self.0.iter()
+    }
+
+    /// `iter_mut` walks the registers in order, mutably.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, R> {
+        self.0.iter_mut()
+    }
+}
+
+impl<R: Readable, const N: usize> RegisterArray<R, N> {
+    /// `read_into` reads the registers in order into `buf`, as when
+    /// draining a FIFO window, and returns how many were read: `N`, or
+    /// `buf.len()` if that is fewer.
+    pub fn read_into(&self, buf: &mut [R::Width]) -> usize {
+        let n = buf.len().min(N);
+        for (slot, reg) in buf.iter_mut().zip(&self.0) {
+            *slot = reg.read();
+        }
+        n
+    }
+}
+
+/// `RegBlock` is a register block of type `T`, such as a `#[repr(C)]`
+/// struct of registers, found at a fixed address. It dereferences to the
+/// block, so that its registers can be used in place.
+///
+/// Its constructor is a `const fn`, so a peripheral at a known address can
+/// be declared once as a `static`. A `static` only hands out shared
+/// references, though, so writing to its registers takes a lock or cell
+/// around it:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// # use bounded_registers::RegBlock;
+/// register! {
+///     Data,
+///     u32,
+///     RW,
+///     Fields [
+///         Byte WIDTH(U8) OFFSET(U0)
+///     ]
+/// }
+///
+/// #[repr(C)]
+/// pub struct UartBlock {
+///     data: Data::Register,
+/// }
+///
+/// static UART: RegBlock<UartBlock> = unsafe { RegBlock::new(0x4000_0000) };
+///
+/// fn main() {
+///     assert_eq!(UART.addr(), 0x4000_0000);
+///     assert_eq!(UART.as_ptr() as usize, 0x4000_0000);
+/// }
+/// ```
+pub struct RegBlock<T> {
+    addr: usize,
+    _block: PhantomData<T>,
+}
+
+impl<T> RegBlock<T> {
+    /// `new` constructs a `RegBlock` over the block at `addr`.
+    ///
+    /// # Safety
+    ///
+    /// `addr` must be the non-NULL, suitably aligned address of a block
+    /// laid out as `T`, valid for as long as the `RegBlock` lives, and
+    /// no other `RegBlock` or reference may alias it while it does.
+    pub const unsafe fn new(addr: usize) -> Self {
+        RegBlock {
+            addr,
+            _block: PhantomData,
+        }
+    }
+
+    /// `addr` returns the address of the block.
+    pub const fn addr(&self) -> usize {
+        self.addr
+    }
+
+    /// `as_ptr` returns a pointer to the block.
+    pub const fn as_ptr(&self) -> *mut T {
+        self.addr as *mut T
+    }
+
+    /// `at_offset` returns the register `R` found `offset` bytes from
+    /// the block's address, an offset which may be negative. It is for
+    /// the registers of a peripheral which don't lay out as a struct,
+    /// such as those documented relative to a secondary anchor.
+    ///
+    /// # Safety
+    ///
+    /// The address `offset` bytes from the block's must be that of a
+    /// register laid out as `R`, suitably aligned, and valid for as long
+    /// as the returned reference lives. Nothing may write to that
+    /// register while the reference lives: no `at_offset_mut` reference
+    /// to it, nor a field of the block which overlaps it.
+    pub unsafe fn at_offset<R>(&self, offset: isize) -> &R {
+        &*self.offset_ptr(offset)
+    }
+
+    /// `at_offset_mut` is `at_offset`, but returns the register mutably.
+    ///
+    /// # Safety
+    ///
+    /// As for `at_offset`, and no other reference to the register may be
+    /// live while the returned one is, whether it was handed out by
+    /// `at_offset`, `at_offset_mut`, or as a field of the block.
+    pub unsafe fn at_offset_mut<R>(&mut self, offset: isize) -> &mut R {
+        &mut *self.offset_ptr(offset)
+    }
+
+    // `is_multiple_of` would raise the minimum supported Rust version.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn offset_ptr<R>(&self, offset: isize) -> *mut R {
+        let addr = self.addr.wrapping_add_signed(offset);
+        debug_assert!(
+            addr % core::mem::align_of::<R>() == 0,
+            "the register at the offset is misaligned",
+        );
+        addr as *mut R
+    }
+
+    /// `transaction` runs `f` over the block as a unit, with a compiler
+    /// fence of the given ordering before and after it, so that the
+    /// compiler can't move the surrounding code's memory accesses into
+    /// or out of it. It is _not_ transactional in hardware: `f`'s writes
+    /// are made one at a time, and an interrupt may observe any of them
+    /// before the rest. As with `core::sync::atomic::compiler_fence`,
+    /// `Ordering::Relaxed` panics.
+    pub fn transaction<V>(
+        &mut self,
+        order: core::sync::atomic::Ordering,
+        f: impl FnOnce(&mut T) -> V,
+    ) -> V {
+        core::sync::atomic::compiler_fence(order);
+        let out = f(self);
+        core::sync::atomic::compiler_fence(order);
+        out
+    }
+}
+
+impl<T> Deref for RegBlock<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<T> DerefMut for RegBlock<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.as_ptr() }
+    }
+}
+
+/// `Modify` is implemented by every writable register, so that generic
+/// code, such as `Batch`, can program it.
+pub trait Modify {
+    type Width;
+
+    /// `modify` takes one or more fields, joined by `+`, and sets
+    /// those fields in the register.
+    fn modify<V: Positioned<Width = Self::Width>>(&mut self, val: V);
+}
+
+/// `RegisterMeta` is implemented by every register, whatever its mode,
+/// and gathers what generic code, such as `ShadowRegister` or a register
+/// dumper, needs to know about one without naming it:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// # use bounded_registers::RegisterMeta;
+/// register! {
+///     Status,
+///     u16,
+///     RO,
+///     Fields [
+///         Ready WIDTH(U1) OFFSET(U0)
+///     ]
+/// }
+///
+/// fn hex_digits<R: RegisterMeta>() -> usize {
+///     (R::BIT_WIDTH / 4) as usize
+/// }
+///
+/// fn main() {
+///     assert_eq!(hex_digits::<Status::Register>(), 4);
+///     assert_eq!(<Status::Register as RegisterMeta>::DEFINED_MASK, 1);
+/// }
+/// ```
+pub trait RegisterMeta {
+    /// The register's numeric type.
+    type Width;
+
+    /// The register's name, as given to `register!`.
+    const NAME: &'static str;
+
+    /// The size of the register's numeric type in bits.
+    const BIT_WIDTH: u32;
+
+    /// The number of bits the register spans, which is `BIT_WIDTH`
+    /// unless fewer were given by its `BITS` clause.
+    const BITS: u32;
+
+    /// The bits which belong to one of the register's fields.
+    const DEFINED_MASK: Self::Width;
+}
+
+/// `ShadowRegister` pairs a register, typically a write-only one, with a
+/// copy of its value kept in memory. `modify` updates the copy and then
+/// writes all of the register's fields to the hardware from it, so that
+/// a write-only register can be modified field by field without the
+/// others being zeroed, and read back from the copy.
+///
+/// The copy is only as good as the value it was started from, and knows
+/// nothing of the hardware changing the register by itself, so it is no
+/// use for status bits. Nor should it be used over a register with
+/// write-one-to-clear fields, since every write repeats the copy's.
+pub struct ShadowRegister<'a, R: Modify> {
+    reg: &'a mut R,
+    shadow: R::Width,
+}
+
+impl<'a, R, W> ShadowRegister<'a, R>
+where
+    R: Modify<Width = W> + RegisterMeta<Width = W>,
+    W: Copy + BitAnd<Output = W> + BitOr<Output = W> + Not<Output = W>,
+{
+    /// `new` wraps `reg`, which is known to hold `init`, such as its
+    /// reset value. The register itself is left untouched.
+    pub fn new(reg: &'a mut R, init: W) -> Self {
+        ShadowRegister { reg, shadow: init }
+    }
+
+    /// `read` returns the copy of the register's value.
+    pub fn read(&self) -> W {
+        self.shadow
+    }
+
+    /// `modify` takes one or more fields, joined by `+`, and sets those
+    /// fields in the copy, leaving the others as they were. It then
+    /// writes every field of the copy to the register.
+    pub fn modify<V: Positioned<Width = W>>(&mut self, val: V) {
+        self.shadow = (self.shadow & !val.mask()) | val.in_position();
+        self.reg.modify(FieldDisj {
+            mask: R::DEFINED_MASK,
+            val: self.shadow & R::DEFINED_MASK,
+        });
+    }
+}
+
+impl<'a, R, W> Modify for ShadowRegister<'a, R>
+where
+    R: Modify<Width = W> + RegisterMeta<Width = W>,
+    W: Copy + BitAnd<Output = W> + BitOr<Output = W> + Not<Output = W>,
+{
+    type Width = W;
+
+    fn modify<V: Positioned<Width = W>>(&mut self, val: V) {
+        ShadowRegister::modify(self, val);
+    }
+}
+
+impl<'a, R, W> Readable for ShadowRegister<'a, R>
+where
+    R: Modify<Width = W> + RegisterMeta<Width = W>,
+    W: Copy
+        + PartialOrd
+        + BitAnd<Output = W>
+        + BitOr<Output = W>
+        + Not<Output = W>
+        + Shr<Output = W>,
+{
+    type Width = W;
+    type Register = R;
+
+    fn read(&self) -> W {
+        self.shadow
+    }
+}
+
+/// `Readable` is implemented by every readable register, so that
+/// generic code, such as a driver which may be handed either a real
+/// register or a `MockRegister`, can inspect it.
+pub trait Readable {
+    type Width: Copy + PartialOrd + BitAnd<Output = Self::Width> + Shr<Output = Self::Width>;
+
+    /// The register whose fields may be read; a register is its own.
+    type Register;
+
+    /// `read` returns the current state of the register.
+    fn read(&self) -> Self::Width;
+
+    /// `get_field` takes a field and sets the value of that field to
+    /// its value in the register.
+    #[allow(clippy::type_complexity)]
+    fn get_field<M, O, U, L, Own>(
+        &self,
+        f: Field<Self::Width, M, O, U, Own, L>,
+    ) -> Option<Field<Self::Width, M, O, U, Own, L>>
+    where
+        Own: BelongsTo<Self::Register>,
+        Self::Width: Default,
+        U: Unsigned
+            + IsGreater<U0, Output = True>
+            + IsGreater<L, Output = True>
+            + ReifyTo<Self::Width>,
+        M: Unsigned,
+        O: Unsigned + ReifyTo<Self::Width>,
+        L: ReifyTo<Self::Width>,
+    {
+        f.set((self.read() >> O::reify()) & U::reify())
+    }
+}
+
+/// `IsSet` is proof that the field `F` was found set, that is, holding
+/// its upper bound, in a register of type `R`. It can only be had from
+/// `check`, so a function which takes one, e.g.
+/// `fn send(_: IsSet<'_, Ctrl::Register, Ctrl::Enable::Field>)`, can only
+/// be called once the field has been checked. The proof borrows the
+/// register it was read from, so that the register can't be modified
+/// while it lives; hardware may still change the field underneath it.
+pub struct IsSet<'a, R, F> {
+    _reg: PhantomData<&'a R>,
+    _field: PhantomData<F>,
+}
+
+// Derived impls would demand the same of `R` and `F`, which neither
+// registers nor fields need provide; the proof itself holds nothing.
+impl<'a, R, F> Clone for IsSet<'a, R, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R, F> Copy for IsSet<'a, R, F> {}
+
+impl<'a, R, F> fmt::Debug for IsSet<'a, R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IsSet").finish()
+    }
+}
+
+impl<'a, R, W, M, O, U, Own, L> IsSet<'a, R, Field<W, M, O, U, Own, L>>
+where
+    W: PartialEq + BitAnd<Output = W> + Shr<Output = W>,
+    U: Unsigned + IsGreater<U0, Output = True> + ReifyTo<W>,
+    M: Unsigned,
+    O: Unsigned + ReifyTo<W>,
+    Own: BelongsTo<R>,
+{
+    /// `check` reads `reg` and returns proof that `f`'s field is set
+    /// in it, or `None` if it isn't.
+    pub fn check<Reg>(reg: &'a Reg, _: Field<W, M, O, U, Own, L>) -> Option<Self>
+    where
+        Reg: Readable<Width = W, Register = R>,
+    {
+        if (reg.read() >> O::reify()) & U::reify() == U::reify() {
+            Some(IsSet {
+                _reg: PhantomData,
+                _field: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// `Batch` queues up modifications to several registers, which may
+/// each be of a different type, and then applies them all, in the
+/// order in which they were queued, with `execute`:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate typenum;
+/// # #[macro_use]
+/// # extern crate bounded_registers;
+/// # use bounded_registers::Batch;
+/// # register! { Ctrl, u8, RW, Fields [Enable WIDTH(U1) OFFSET(U0)] }
+/// # register! { Irq, u8, RW, Fields [Mask WIDTH(U1) OFFSET(U3)] }
+/// # fn main() {
+/// # let mut ctrl = Ctrl::Register::new(0);
+/// # let mut irq = Irq::Register::new(0xFF);
+/// Batch::new()
+///     .modify(&mut ctrl, Ctrl::Enable::Set)
+///     .modify(&mut irq, Irq::Mask::Clear)
+///     .execute();
+/// # assert_eq!(ctrl.read(), 0b0000_0001);
+/// # assert_eq!(irq.read(), 0b1111_0111);
+/// # }
+/// ```
+///
+/// The queue is a chain of types rather than a buffer, so it needs no
+/// allocation.
+#[must_use = "a batch does nothing until it is executed"]
+pub struct Batch<Ops>(Ops);
+
+/// A modification queued in a `Batch`.
+pub struct Pending<'a, R, V> {
+    reg: &'a mut R,
+    val: V,
+}
+
+/// `Execute` is implemented by the chain of modifications queued in a
+/// `Batch`.
+pub trait Execute {
+    fn execute(self);
+}
+
+impl Execute for () {
+    fn execute(self) {}
+}
+
+impl<Ops, R, V> Execute for (Ops, Pending<'_, R, V>)
+where
+    Ops: Execute,
+    R: Modify,
+    V: Positioned<Width = R::Width>,
+{
+    fn execute(self) {
+        let (queued, pending) = self;
+        queued.execute();
+        pending.reg.modify(pending.val);
+    }
+}
+
+impl Batch<()> {
+    /// `new` makes an empty batch.
+    pub fn new() -> Self {
+        Batch(())
+    }
+}
+
+impl Default for Batch<()> {
+    fn default() -> Self {
+        Batch::new()
+    }
+}
+
+impl<Ops: Execute> Batch<Ops> {
+    /// `modify` queues `val` to be applied to `reg`, after everything
+    /// queued before it.
+    pub fn modify<R, V>(self, reg: &mut R, val: V) -> Batch<(Ops, Pending<'_, R, V>)>
+    where
+        R: Modify,
+        V: Positioned<Width = R::Width>,
+    {
+        Batch((self.0, Pending { reg, val }))
+    }
+
+    /// `execute` applies every queued modification, in the order in
+    /// which they were queued.
+    pub fn execute(self) {
+        self.0.execute()
+    }
+}
+
 pub trait Pointer {
     /// # Safety
     ///
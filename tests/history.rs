@@ -0,0 +1,58 @@
+//! Every `modify` of an `RW` register records into the same global
+//! ring, so the history is tested in a binary of its own, where no
+//! test running alongside can write into it.
+
+#![cfg(feature = "history")]
+
+#[macro_use]
+extern crate typenum;
+#[macro_use]
+extern crate bounded_registers;
+
+use bounded_registers::history;
+
+register! {
+    Logged,
+    u16,
+    RW,
+    Fields [
+        Low WIDTH(U8) OFFSET(U0),
+        High WIDTH(U8) OFFSET(U8)
+    ]
+}
+
+register! {
+    Signed,
+    i16,
+    RW,
+    Fields [
+        Low WIDTH(U1) OFFSET(U0)
+    ]
+}
+
+#[test]
+fn test_history() {
+    history::clear();
+
+    let mut reg = Logged::Register::new(0);
+    reg.modify(Logged::Low::Set);
+    reg.modify(Logged::High::Set);
+    reg.modify(Logged::Low::Clear);
+    reg.toggle(Logged::High::Read);
+    unsafe { reg.modify_with(|old| old | 0x0f00) };
+
+    // -1 is recorded as the register's 16 bits, not sign-extended.
+    let mut signed = Signed::Register::new(-1);
+    signed.modify(Signed::Low::Clear);
+
+    let history = history::history().unwrap();
+    let logged = history.iter().map(|r| (r.register, r.old, r.new));
+    assert!(logged.eq([
+        ("Logged", 0, 0x00ff),
+        ("Logged", 0x00ff, 0xffff),
+        ("Logged", 0xffff, 0xff00),
+        ("Logged", 0xff00, 0x0000),
+        ("Logged", 0x0000, 0x0f00),
+        ("Signed", 0xffff, 0xfffe),
+    ]));
+}